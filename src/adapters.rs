@@ -0,0 +1,2047 @@
+//! Adapters that wrap a [Senerator](../gen/trait.Senerator.html), and are Senerators themselves.
+//! These are created by the methods on the [Senerator](../gen/trait.Senerator.html) trait.
+
+use gen::{Callable, ExactSizeSenerator, Futerator, Futor, Senerator, Senor, State};
+use std::collections::VecDeque;
+use std::convert::TryInto;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Generator;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Advances a Senerator as a Futerator, throwing away the yielded item.
+#[inline]
+fn discard_yield<Y, R>(state: Senor<Y, R>) -> Futor<R> {
+    match state? {
+        State::Yield(_) => Some(State::Yield(())),
+        State::Return(r) => Some(State::Return(r)),
+    }
+}
+
+/// Resumes a Futerator until it returns, throwing away whatever it yields.
+#[inline]
+fn finish<G: Futerator>(gen: &mut G) -> Option<G::Return> {
+    loop {
+        if let State::Return(r) = gen.resume()? {
+            return Some(r);
+        }
+    }
+}
+
+/// Yields the yielded items of the underlying Senerator together with their index, counting from a given start.
+/// Created by [`enumerate_yields_from`](../gen/trait.Senerator.html#method.enumerate_yields_from).
+pub struct EnumerateFrom<G> {
+    gen: G,
+    count: usize,
+}
+
+/// Yields the yielded items of the underlying Senerator together with their index, counting from 0.
+/// Created by [`enumerate`](../gen/trait.Senerator.html#method.enumerate).
+pub type Enumerate<G> = EnumerateFrom<G>;
+
+impl<G> EnumerateFrom<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, start: usize) -> Self {
+        EnumerateFrom { gen, count: start }
+    }
+}
+
+impl<G> Futerator for EnumerateFrom<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for EnumerateFrom<G>
+where
+    G: Senerator,
+{
+    type Yield = (usize, G::Yield);
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                let index = self.count;
+                self.count += 1;
+                Some(State::Yield((index, y)))
+            }
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// A Senerator with the ability to look at the next state of the underlying Senerator, without consuming it.
+/// Created by [`peekable`](../gen/trait.Senerator.html#method.peekable).
+pub struct Peekable<G: Senerator> {
+    gen: G,
+    peeked: Option<Senor<G::Yield, G::Return>>,
+}
+
+impl<G: Senerator> Peekable<G> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Peekable { gen, peeked: None }
+    }
+
+    /// Buffers the next state of the underlying Senerator, if it isn't buffered already.
+    #[inline]
+    fn peek_state(&mut self) -> Option<&State<G::Yield, G::Return>> {
+        let gen = &mut self.gen;
+        self.peeked
+            .get_or_insert_with(|| gen.resume_with_yield())
+            .as_ref()
+    }
+
+    /// Returns true if the next call to [`resume_with_yield`](../gen/trait.Senerator.html#tymethod.resume_with_yield) returns the returned item.
+    #[inline]
+    pub fn peek_is_return(&mut self) -> bool {
+        match self.peek_state() {
+            Some(State::Return(_)) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns a reference to the next yielded item, without consuming it.
+    /// Returns None if the next state is the returned item, or if the underlying Senerator is exhausted.
+    /// A buffered returned item is handed out by the next call to [`resume_with_yield`](../gen/trait.Senerator.html#tymethod.resume_with_yield).
+    #[inline]
+    pub fn peek(&mut self) -> Option<&G::Yield> {
+        match self.peek_state() {
+            Some(State::Yield(y)) => Some(y),
+            _ => None,
+        }
+    }
+}
+
+impl<G> Futerator for Peekable<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Peekable<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.peeked.take() {
+            Some(state) => state,
+            None => self.gen.resume_with_yield(),
+        }
+    }
+}
+
+/// A Senerator that returns None forever after the underlying Senerator returned, or was exhausted,
+/// without ever resuming the underlying Senerator again.
+/// Created by [`fuse`](../gen/trait.Senerator.html#method.fuse).
+pub struct Fuse<G> {
+    gen: G,
+    done: bool,
+}
+
+impl<G> Fuse<G> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Fuse { gen, done: false }
+    }
+}
+
+impl<G> Futerator for Fuse<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Fuse<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.done {
+            return None;
+        }
+
+        match self.gen.resume_with_yield() {
+            Some(State::Yield(y)) => Some(State::Yield(y)),
+            state => {
+                self.done = true;
+                state
+            }
+        }
+    }
+}
+
+impl<G> ExactSizeSenerator for Fuse<G>
+where
+    G: ExactSizeSenerator,
+{
+    #[inline]
+    fn remaining(&self) -> usize {
+        if self.done {
+            0
+        } else {
+            self.gen.remaining()
+        }
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.done || self.gen.is_done()
+    }
+}
+
+/// Yields overlapping pairs of the yielded items of the underlying Senerator.
+/// Created by [`tuple_windows_2`](../gen/trait.Senerator.html#method.tuple_windows_2).
+pub struct TupleWindows2<G: Senerator> {
+    gen: G,
+    prev: Option<G::Yield>,
+}
+
+impl<G: Senerator> TupleWindows2<G> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        TupleWindows2 { gen, prev: None }
+    }
+}
+
+impl<G> Futerator for TupleWindows2<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for TupleWindows2<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Yield = (G::Yield, G::Yield);
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    if let Some(prev) = self.prev.replace(y.clone()) {
+                        return Some(State::Yield((prev, y)));
+                    }
+                }
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// The rest of a Senerator, of which some yielded items already have been taken off.
+/// If the underlying Senerator returned while taking the items off, the returned item is held on to, and returned on the first resume.
+/// Created by [`split_first_yield`](../gen/trait.Senerator.html#method.split_first_yield) and [`split_at`](../gen/trait.Senerator.html#method.split_at).
+pub struct RestSenerator<G: Senerator> {
+    gen: G,
+    ret: Option<G::Return>,
+}
+
+impl<G: Senerator> RestSenerator<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, ret: Option<G::Return>) -> Self {
+        RestSenerator { gen, ret }
+    }
+}
+
+impl<G> Futerator for RestSenerator<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for RestSenerator<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.ret.take() {
+            Some(r) => Some(State::Return(r)),
+            None => self.gen.resume_with_yield(),
+        }
+    }
+}
+
+/// Yields the running accumulation of the yielded items of the underlying Senerator.
+/// Created by [`accumulate_yields`](../gen/trait.Senerator.html#method.accumulate_yields).
+pub struct Accumulate<G: Senerator, F> {
+    gen: G,
+    f: F,
+    acc: Option<G::Yield>,
+}
+
+impl<G: Senerator, F> Accumulate<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, f: F) -> Self {
+        Accumulate { gen, f, acc: None }
+    }
+}
+
+impl<G, F> Futerator for Accumulate<G, F>
+where
+    G: Senerator,
+    G::Yield: Clone,
+    F: FnMut(G::Yield, G::Yield) -> G::Yield,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F> Senerator for Accumulate<G, F>
+where
+    G: Senerator,
+    G::Yield: Clone,
+    F: FnMut(G::Yield, G::Yield) -> G::Yield,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                let acc = match self.acc.take() {
+                    Some(acc) => (self.f)(acc, y),
+                    None => y,
+                };
+                self.acc = Some(acc.clone());
+                Some(State::Yield(acc))
+            }
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// Yields the yielded items of the underlying Senerator, fallibly converted into `T`.
+/// Created by [`try_into_yields`](../gen/trait.Senerator.html#method.try_into_yields).
+pub struct TryIntoYields<G, T> {
+    gen: G,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<G, T> TryIntoYields<G, T> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        TryIntoYields {
+            gen,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<G, T> Futerator for TryIntoYields<G, T>
+where
+    G: Senerator,
+    G::Yield: TryInto<T>,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, T> Senerator for TryIntoYields<G, T>
+where
+    G: Senerator,
+    G::Yield: TryInto<T>,
+{
+    type Yield = Result<T, <G::Yield as TryInto<T>>::Error>;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => Some(State::Yield(y.try_into())),
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// A Futerator that folds the yielded items of the underlying Senerator, and returns the final accumulator.
+/// The returned item of the underlying Senerator is thrown away.
+/// Created by [`sink_into_return`](../gen/trait.Senerator.html#method.sink_into_return).
+pub struct SinkReturn<G, R2, F> {
+    gen: G,
+    f: F,
+    acc: Option<R2>,
+}
+
+impl<G, R2, F> SinkReturn<G, R2, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, init: R2, f: F) -> Self {
+        SinkReturn {
+            gen,
+            f,
+            acc: Some(init),
+        }
+    }
+}
+
+impl<G, R2, F> Futerator for SinkReturn<G, R2, F>
+where
+    G: Senerator,
+    F: FnMut(R2, G::Yield) -> R2,
+{
+    type Return = R2;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                let acc = self.acc.take()?;
+                self.acc = Some((self.f)(acc, y));
+                Some(State::Yield(()))
+            }
+            State::Return(_) => Some(State::Return(self.acc.take()?)),
+        }
+    }
+}
+
+/// Yields all the items of each yielded Iterator of the underlying Senerator.
+/// Created by [`flatten_iter`](../gen/trait.Senerator.html#method.flatten_iter).
+pub struct FlattenIter<G>
+where
+    G: Senerator,
+    G::Yield: IntoIterator,
+{
+    gen: G,
+    current: Option<<G::Yield as IntoIterator>::IntoIter>,
+}
+
+impl<G> FlattenIter<G>
+where
+    G: Senerator,
+    G::Yield: IntoIterator,
+{
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        FlattenIter { gen, current: None }
+    }
+}
+
+impl<G> Futerator for FlattenIter<G>
+where
+    G: Senerator,
+    G::Yield: IntoIterator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for FlattenIter<G>
+where
+    G: Senerator,
+    G::Yield: IntoIterator,
+{
+    type Yield = <G::Yield as IntoIterator>::Item;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            if let Some(ref mut iter) = self.current {
+                if let Some(item) = iter.next() {
+                    return Some(State::Yield(item));
+                }
+            }
+
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => self.current = Some(y.into_iter()),
+                State::Return(r) => {
+                    self.current = None;
+                    return Some(State::Return(r));
+                }
+            }
+        }
+    }
+}
+
+/// Maps each state of the underlying Senerator, together with the index of the step.
+/// Created by [`map_state_indexed`](../gen/trait.Senerator.html#method.map_state_indexed).
+pub struct MapStateIndexed<G, F> {
+    gen: G,
+    f: F,
+    index: usize,
+    done: bool,
+}
+
+impl<G, F> MapStateIndexed<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, f: F) -> Self {
+        MapStateIndexed {
+            gen,
+            f,
+            index: 0,
+            done: false,
+        }
+    }
+}
+
+impl<G, F, Y2, R2> Futerator for MapStateIndexed<G, F>
+where
+    G: Senerator,
+    F: FnMut(usize, State<G::Yield, G::Return>) -> State<Y2, R2>,
+{
+    type Return = R2;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F, Y2, R2> Senerator for MapStateIndexed<G, F>
+where
+    G: Senerator,
+    F: FnMut(usize, State<G::Yield, G::Return>) -> State<Y2, R2>,
+{
+    type Yield = Y2;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.done {
+            return None;
+        }
+
+        let state = self.gen.resume_with_yield()?;
+        let index = self.index;
+        self.index += 1;
+
+        let mapped = (self.f)(index, state);
+        if let State::Return(_) = mapped {
+            self.done = true;
+        }
+        Some(mapped)
+    }
+}
+
+/// Calls a closure with a reference to each state of the underlying Senerator, and passes the state through.
+/// Created by [`watch`](../gen/trait.Senerator.html#method.watch).
+pub struct Watch<G, F> {
+    gen: G,
+    on_step: F,
+}
+
+impl<G, F> Watch<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, on_step: F) -> Self {
+        Watch { gen, on_step }
+    }
+}
+
+impl<G, F> Futerator for Watch<G, F>
+where
+    G: Senerator,
+    F: FnMut(&State<G::Yield, G::Return>),
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F> Senerator for Watch<G, F>
+where
+    G: Senerator,
+    F: FnMut(&State<G::Yield, G::Return>),
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        let state = self.gen.resume_with_yield()?;
+        (self.on_step)(&state);
+        Some(state)
+    }
+}
+
+/// Passes the yielded items of the underlying Senerator through, while folding over them.
+/// The returned item gets mapped, using the final state of the fold.
+/// Created by [`map_return_with_yields`](../gen/trait.Senerator.html#method.map_return_with_yields).
+pub struct MapReturnWithYields<G, St, F, Fin> {
+    gen: G,
+    state: Option<St>,
+    f: F,
+    finish: Option<Fin>,
+}
+
+impl<G, St, F, Fin> MapReturnWithYields<G, St, F, Fin> {
+    #[inline]
+    pub(crate) fn new(gen: G, init: St, f: F, finish: Fin) -> Self {
+        MapReturnWithYields {
+            gen,
+            state: Some(init),
+            f,
+            finish: Some(finish),
+        }
+    }
+}
+
+impl<G, St, F, Fin, R2> Futerator for MapReturnWithYields<G, St, F, Fin>
+where
+    G: Senerator,
+    F: FnMut(&mut St, &G::Yield),
+    Fin: FnOnce(St, G::Return) -> R2,
+{
+    type Return = R2;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, St, F, Fin, R2> Senerator for MapReturnWithYields<G, St, F, Fin>
+where
+    G: Senerator,
+    F: FnMut(&mut St, &G::Yield),
+    Fin: FnOnce(St, G::Return) -> R2,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                (self.f)(self.state.as_mut()?, &y);
+                Some(State::Yield(y))
+            }
+            State::Return(r) => {
+                let (state, finish) = (self.state.take()?, self.finish.take()?);
+                Some(State::Return(finish(state, r)))
+            }
+        }
+    }
+}
+
+/// Mutates each yielded item of the underlying Senerator in place, before yielding it.
+/// Created by [`map_in_place`](../gen/trait.Senerator.html#method.map_in_place).
+pub struct MapInPlace<G, F> {
+    gen: G,
+    f: F,
+}
+
+impl<G, F> MapInPlace<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, f: F) -> Self {
+        MapInPlace { gen, f }
+    }
+}
+
+impl<G, F> Futerator for MapInPlace<G, F>
+where
+    G: Senerator,
+    F: FnMut(&mut G::Yield),
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F> Senerator for MapInPlace<G, F>
+where
+    G: Senerator,
+    F: FnMut(&mut G::Yield),
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(mut y) => {
+                (self.f)(&mut y);
+                Some(State::Yield(y))
+            }
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// A Senerator that can be poisoned, after which the underlying Senerator is never resumed again.
+/// Created by [`guarded`](../gen/trait.Senerator.html#method.guarded).
+pub struct Guarded<G> {
+    gen: G,
+    poisoned: bool,
+}
+
+impl<G> Guarded<G> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Guarded {
+            gen,
+            poisoned: false,
+        }
+    }
+
+    /// Marks `Self` as exhausted. Any further resumes return None, without resuming the underlying Senerator.
+    #[inline]
+    pub fn poison(&mut self) {
+        self.poisoned = true;
+    }
+
+    /// Returns true if `Self` has been poisoned.
+    #[inline]
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+}
+
+impl<G> Futerator for Guarded<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Guarded<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.poisoned {
+            return None;
+        }
+        self.gen.resume_with_yield()
+    }
+}
+
+/// A Senerator that stops resuming the underlying Senerator once a shared flag is set.
+/// Created by [`interruptible`](../gen/trait.Senerator.html#method.interruptible).
+pub struct Interruptible<G> {
+    gen: G,
+    flag: Arc<AtomicBool>,
+}
+
+impl<G> Interruptible<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, flag: Arc<AtomicBool>) -> Self {
+        Interruptible { gen, flag }
+    }
+}
+
+impl<G> Futerator for Interruptible<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Interruptible<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.flag.load(Ordering::SeqCst) {
+            return None;
+        }
+        self.gen.resume_with_yield()
+    }
+}
+
+/// Yields each item of a successfully yielded Iterator of the underlying Senerator as `Ok`, and yielded errors as is.
+/// Created by [`try_flatten_yields`](../gen/trait.Senerator.html#method.try_flatten_yields).
+pub struct TryFlattenYields<G, I: IntoIterator> {
+    gen: G,
+    current: Option<I::IntoIter>,
+}
+
+impl<G, I: IntoIterator> TryFlattenYields<G, I> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        TryFlattenYields { gen, current: None }
+    }
+}
+
+impl<G, I, E> Futerator for TryFlattenYields<G, I>
+where
+    G: Senerator<Yield = Result<I, E>>,
+    I: IntoIterator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, I, E> Senerator for TryFlattenYields<G, I>
+where
+    G: Senerator<Yield = Result<I, E>>,
+    I: IntoIterator,
+{
+    type Yield = Result<I::Item, E>;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            if let Some(ref mut iter) = self.current {
+                if let Some(item) = iter.next() {
+                    return Some(State::Yield(Ok(item)));
+                }
+            }
+
+            match self.gen.resume_with_yield()? {
+                State::Yield(Ok(iter)) => self.current = Some(iter.into_iter()),
+                State::Yield(Err(e)) => {
+                    self.current = None;
+                    return Some(State::Yield(Err(e)));
+                }
+                State::Return(r) => {
+                    self.current = None;
+                    return Some(State::Return(r));
+                }
+            }
+        }
+    }
+}
+
+/// Yields a filler item before, between and after the yielded items of the underlying Senerator.
+/// Created by [`alternate`](../gen/trait.Senerator.html#method.alternate).
+pub struct Alternate<G: Senerator> {
+    gen: G,
+    filler: G::Yield,
+    filler_next: bool,
+}
+
+impl<G: Senerator> Alternate<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, filler: G::Yield) -> Self {
+        Alternate {
+            gen,
+            filler,
+            filler_next: true,
+        }
+    }
+}
+
+impl<G> Futerator for Alternate<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Alternate<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.filler_next {
+            self.filler_next = false;
+            return Some(State::Yield(self.filler.clone()));
+        }
+
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                self.filler_next = true;
+                Some(State::Yield(y))
+            }
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// Reports the progress of the underlying Senerator after each yielded item, passing everything through.
+/// Created by [`with_progress`](../gen/trait.Senerator.html#method.with_progress).
+pub struct WithProgress<G, F> {
+    gen: G,
+    on_progress: F,
+    completed: usize,
+    total: usize,
+}
+
+impl<G, F> WithProgress<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, total: usize, on_progress: F) -> Self {
+        WithProgress {
+            gen,
+            on_progress,
+            completed: 0,
+            total,
+        }
+    }
+}
+
+impl<G, F> Futerator for WithProgress<G, F>
+where
+    G: Senerator,
+    F: FnMut(usize, usize),
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F> Senerator for WithProgress<G, F>
+where
+    G: Senerator,
+    F: FnMut(usize, usize),
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        let state = self.gen.resume_with_yield()?;
+
+        if let State::Yield(_) = state {
+            self.completed += 1;
+            (self.on_progress)(self.completed, self.total);
+        }
+        Some(state)
+    }
+}
+
+/// Yields every other yielded item of the underlying Senerator.
+/// Created by [`every_other`](../gen/trait.Senerator.html#method.every_other).
+pub struct EveryOther<G> {
+    gen: G,
+    keep_next: bool,
+}
+
+impl<G> EveryOther<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, keep_first: bool) -> Self {
+        EveryOther {
+            gen,
+            keep_next: keep_first,
+        }
+    }
+}
+
+impl<G> Futerator for EveryOther<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for EveryOther<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    self.keep_next = !self.keep_next;
+                    if !self.keep_next {
+                        return Some(State::Yield(y));
+                    }
+                }
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// Maps the yielded items of the underlying Senerator, passing the returned item through.
+/// Created by [`map_yield`](../gen/trait.Senerator.html#method.map_yield).
+pub struct MapYield<G, F> {
+    gen: G,
+    f: F,
+}
+
+impl<G, F> MapYield<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, f: F) -> Self {
+        MapYield { gen, f }
+    }
+}
+
+impl<G, F, B> Futerator for MapYield<G, F>
+where
+    G: Senerator,
+    F: FnMut(G::Yield) -> B,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F, B> Senerator for MapYield<G, F>
+where
+    G: Senerator,
+    F: FnMut(G::Yield) -> B,
+{
+    type Yield = B;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => Some(State::Yield((self.f)(y))),
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// Yields only the yielded items of the underlying Senerator that pass a predicate, the returned item is never filtered out.
+/// Created by [`filter_yield`](../gen/trait.Senerator.html#method.filter_yield).
+pub struct FilterYield<G, P> {
+    gen: G,
+    predicate: P,
+}
+
+impl<G, P> FilterYield<G, P> {
+    #[inline]
+    pub(crate) fn new(gen: G, predicate: P) -> Self {
+        FilterYield { gen, predicate }
+    }
+}
+
+impl<G, P> Futerator for FilterYield<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, P> Senerator for FilterYield<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    if (self.predicate)(&y) {
+                        return Some(State::Yield(y));
+                    }
+                }
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// Yields the yielded items of the underlying Senerator while they pass a predicate, and then returns its returned item.
+/// Created by [`take_while`](../gen/trait.Senerator.html#method.take_while).
+pub struct TakeWhileGen<G, P> {
+    gen: G,
+    predicate: P,
+}
+
+impl<G, P> TakeWhileGen<G, P> {
+    #[inline]
+    pub(crate) fn new(gen: G, predicate: P) -> Self {
+        TakeWhileGen { gen, predicate }
+    }
+}
+
+impl<G, P> Futerator for TakeWhileGen<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, P> Senerator for TakeWhileGen<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                if (self.predicate)(&y) {
+                    return Some(State::Yield(y));
+                }
+            }
+            State::Return(r) => return Some(State::Return(r)),
+        }
+
+        loop {
+            if let State::Return(r) = self.gen.resume_with_yield()? {
+                return Some(State::Return(r));
+            }
+        }
+    }
+}
+
+/// Skips the leading yielded items of the underlying Senerator while they pass a predicate, and passes everything after that through.
+/// Created by [`skip_while`](../gen/trait.Senerator.html#method.skip_while).
+pub struct SkipWhileGen<G, P> {
+    gen: G,
+    predicate: P,
+    skipping: bool,
+}
+
+impl<G, P> SkipWhileGen<G, P> {
+    #[inline]
+    pub(crate) fn new(gen: G, predicate: P) -> Self {
+        SkipWhileGen {
+            gen,
+            predicate,
+            skipping: true,
+        }
+    }
+}
+
+impl<G, P> Futerator for SkipWhileGen<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, P> Senerator for SkipWhileGen<G, P>
+where
+    G: Senerator,
+    P: FnMut(&G::Yield) -> bool,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    if !self.skipping || !(self.predicate)(&y) {
+                        self.skipping = false;
+                        return Some(State::Yield(y));
+                    }
+                }
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// Calls a closure with a reference to each yielded item of the underlying Senerator, before yielding it.
+/// Created by [`inspect`](../gen/trait.Senerator.html#method.inspect).
+pub struct Inspect<G, F> {
+    gen: G,
+    f: F,
+}
+
+impl<G, F> Inspect<G, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, f: F) -> Self {
+        Inspect { gen, f }
+    }
+}
+
+impl<G, F> Futerator for Inspect<G, F>
+where
+    G: Senerator,
+    F: FnMut(&G::Yield),
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, F> Senerator for Inspect<G, F>
+where
+    G: Senerator,
+    F: FnMut(&G::Yield),
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        let state = self.gen.resume_with_yield()?;
+
+        if let State::Yield(ref y) = state {
+            (self.f)(y);
+        }
+        Some(state)
+    }
+}
+
+/// Yields the results of a stateful closure over the yielded items of the underlying Senerator.
+/// Created by [`scan`](../gen/trait.Senerator.html#method.scan).
+pub struct Scan<G, St, F> {
+    gen: G,
+    state: St,
+    f: F,
+}
+
+impl<G, St, F> Scan<G, St, F> {
+    #[inline]
+    pub(crate) fn new(gen: G, state: St, f: F) -> Self {
+        Scan { gen, state, f }
+    }
+}
+
+impl<G, St, F, B> Futerator for Scan<G, St, F>
+where
+    G: Senerator,
+    F: FnMut(&mut St, G::Yield) -> Option<B>,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G, St, F, B> Senerator for Scan<G, St, F>
+where
+    G: Senerator,
+    F: FnMut(&mut St, G::Yield) -> Option<B>,
+{
+    type Yield = B;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                if let Some(b) = (self.f)(&mut self.state, y) {
+                    return Some(State::Yield(b));
+                }
+            }
+            State::Return(r) => return Some(State::Return(r)),
+        }
+
+        loop {
+            if let State::Return(r) = self.gen.resume_with_yield()? {
+                return Some(State::Return(r));
+            }
+        }
+    }
+}
+
+/// Yields the first yielded item of the underlying Senerator, and then every `step`th yielded item.
+/// Created by [`step_by`](../gen/trait.Senerator.html#method.step_by).
+pub struct StepBy<G> {
+    gen: G,
+    step: usize,
+    first: bool,
+}
+
+impl<G> StepBy<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, step: usize) -> Self {
+        assert!(step != 0, "step_by: step must be larger than 0");
+        StepBy {
+            gen,
+            step,
+            first: true,
+        }
+    }
+}
+
+impl<G> Futerator for StepBy<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for StepBy<G>
+where
+    G: Senerator,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.first {
+            self.first = false;
+            return self.gen.resume_with_yield();
+        }
+
+        for _ in 1..self.step {
+            if let State::Return(r) = self.gen.resume_with_yield()? {
+                return Some(State::Return(r));
+            }
+        }
+        self.gen.resume_with_yield()
+    }
+}
+
+/// Yields the yielded items of the underlying Senerator in chunks.
+/// Created by [`chunks`](../gen/trait.Senerator.html#method.chunks).
+pub struct Chunks<G: Senerator> {
+    gen: G,
+    size: usize,
+    chunk: Vec<G::Yield>,
+    ret: Option<G::Return>,
+}
+
+impl<G: Senerator> Chunks<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, size: usize) -> Self {
+        assert!(size != 0, "chunks: size must be larger than 0");
+        Chunks {
+            gen,
+            size,
+            chunk: Vec::with_capacity(size),
+            ret: None,
+        }
+    }
+}
+
+impl<G> Futerator for Chunks<G>
+where
+    G: Senerator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Chunks<G>
+where
+    G: Senerator,
+{
+    type Yield = Vec<G::Yield>;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if let Some(r) = self.ret.take() {
+            return Some(State::Return(r));
+        }
+
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    self.chunk.push(y);
+                    if self.chunk.len() == self.size {
+                        let chunk = mem::replace(&mut self.chunk, Vec::with_capacity(self.size));
+                        return Some(State::Yield(chunk));
+                    }
+                }
+                State::Return(r) => {
+                    if self.chunk.is_empty() {
+                        return Some(State::Return(r));
+                    }
+                    self.ret = Some(r);
+                    return Some(State::Yield(mem::replace(&mut self.chunk, Vec::new())));
+                }
+            }
+        }
+    }
+}
+
+/// Yields overlapping windows over the yielded items of the underlying Senerator.
+/// Created by [`windows`](../gen/trait.Senerator.html#method.windows).
+pub struct Windows<G: Senerator> {
+    gen: G,
+    size: usize,
+    window: VecDeque<G::Yield>,
+}
+
+impl<G: Senerator> Windows<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, size: usize) -> Self {
+        assert!(size != 0, "windows: size must be larger than 0");
+        Windows {
+            gen,
+            size,
+            window: VecDeque::with_capacity(size),
+        }
+    }
+}
+
+impl<G> Futerator for Windows<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Windows<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Yield = Vec<G::Yield>;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    if self.window.len() == self.size {
+                        self.window.pop_front();
+                    }
+                    self.window.push_back(y);
+
+                    if self.window.len() == self.size {
+                        return Some(State::Yield(self.window.iter().cloned().collect()));
+                    }
+                }
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// Yields a separator between the yielded items of the underlying Senerator.
+/// Created by [`intersperse`](../gen/trait.Senerator.html#method.intersperse).
+pub struct Intersperse<G: Senerator> {
+    gen: G,
+    sep: G::Yield,
+    next: Option<G::Yield>,
+    started: bool,
+}
+
+impl<G: Senerator> Intersperse<G> {
+    #[inline]
+    pub(crate) fn new(gen: G, sep: G::Yield) -> Self {
+        Intersperse {
+            gen,
+            sep,
+            next: None,
+            started: false,
+        }
+    }
+}
+
+impl<G> Futerator for Intersperse<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Intersperse<G>
+where
+    G: Senerator,
+    G::Yield: Clone,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if let Some(y) = self.next.take() {
+            return Some(State::Yield(y));
+        }
+
+        match self.gen.resume_with_yield()? {
+            State::Yield(y) => {
+                if !self.started {
+                    self.started = true;
+                    return Some(State::Yield(y));
+                }
+                self.next = Some(y);
+                Some(State::Yield(self.sep.clone()))
+            }
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// Collapses consecutive equal yielded items of the underlying Senerator into one.
+/// Created by [`dedup`](../gen/trait.Senerator.html#method.dedup).
+pub struct Dedup<G: Senerator> {
+    gen: G,
+    last: Option<G::Yield>,
+    ret: Option<G::Return>,
+}
+
+impl<G: Senerator> Dedup<G> {
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Dedup {
+            gen,
+            last: None,
+            ret: None,
+        }
+    }
+}
+
+impl<G> Futerator for Dedup<G>
+where
+    G: Senerator,
+    G::Yield: PartialEq,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Dedup<G>
+where
+    G: Senerator,
+    G::Yield: PartialEq,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if let Some(r) = self.ret.take() {
+            return Some(State::Return(r));
+        }
+
+        loop {
+            match self.gen.resume_with_yield() {
+                Some(State::Yield(y)) => match self.last.take() {
+                    Some(last) => {
+                        if last == y {
+                            self.last = Some(last);
+                        } else {
+                            self.last = Some(y);
+                            return Some(State::Yield(last));
+                        }
+                    }
+                    None => self.last = Some(y),
+                },
+                Some(State::Return(r)) => match self.last.take() {
+                    Some(last) => {
+                        self.ret = Some(r);
+                        return Some(State::Yield(last));
+                    }
+                    None => return Some(State::Return(r)),
+                },
+                None => return self.last.take().map(State::Yield),
+            }
+        }
+    }
+}
+
+/// Yields pairs of the yielded items of two Senerators, and returns both their returned items.
+/// Created by [`zip`](fn.zip.html).
+pub struct Zip<A, B> {
+    a: A,
+    b: B,
+}
+
+/// Zips two Senerators together, yielding pairs of their yielded items.
+/// On each resume, `a` is resumed first, and then `b`.
+/// As soon as either one returns, the other one is driven to completion, throwing away its yielded items,
+/// and the new Senerator returns `(a's return, b's return)`. If `a` yielded an item but `b` returned, that item is thrown away as well.
+#[inline]
+pub fn zip<A, B>(a: A, b: B) -> Zip<A, B>
+where
+    A: Senerator,
+    B: Senerator,
+{
+    Zip { a, b }
+}
+
+impl<A, B> Futerator for Zip<A, B>
+where
+    A: Senerator,
+    B: Senerator,
+{
+    type Return = (A::Return, B::Return);
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<A, B> Senerator for Zip<A, B>
+where
+    A: Senerator,
+    B: Senerator,
+{
+    type Yield = (A::Yield, B::Yield);
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        let a = match self.a.resume_with_yield()? {
+            State::Yield(a) => a,
+            State::Return(ra) => return Some(State::Return((ra, finish(&mut self.b)?))),
+        };
+
+        match self.b.resume_with_yield()? {
+            State::Yield(b) => Some(State::Yield((a, b))),
+            State::Return(rb) => Some(State::Return((finish(&mut self.a)?, rb))),
+        }
+    }
+}
+
+/// Yields the results of a closure called with the yielded items of two Senerators, and returns both their returned items.
+/// Created by [`zip_with`](fn.zip_with.html).
+pub struct ZipWith<A, B, F> {
+    zip: Zip<A, B>,
+    f: F,
+}
+
+/// Zips two Senerators together like [`zip`](fn.zip.html) does, but yields the result of the closure called with both yielded items.
+#[inline]
+pub fn zip_with<A, B, F, C>(a: A, b: B, f: F) -> ZipWith<A, B, F>
+where
+    A: Senerator,
+    B: Senerator,
+    F: FnMut(A::Yield, B::Yield) -> C,
+{
+    ZipWith { zip: zip(a, b), f }
+}
+
+impl<A, B, F, C> Futerator for ZipWith<A, B, F>
+where
+    A: Senerator,
+    B: Senerator,
+    F: FnMut(A::Yield, B::Yield) -> C,
+{
+    type Return = (A::Return, B::Return);
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<A, B, F, C> Senerator for ZipWith<A, B, F>
+where
+    A: Senerator,
+    B: Senerator,
+    F: FnMut(A::Yield, B::Yield) -> C,
+{
+    type Yield = C;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        match self.zip.resume_with_yield()? {
+            State::Yield((a, b)) => Some(State::Yield((self.f)(a, b))),
+            State::Return(r) => Some(State::Return(r)),
+        }
+    }
+}
+
+/// Alternates between the yielded items of two Senerators, and returns both their returned items.
+/// Created by [`interleave`](fn.interleave.html).
+pub struct Interleave<A: Senerator, B: Senerator> {
+    a: A,
+    b: B,
+    ret_a: Option<A::Return>,
+    ret_b: Option<B::Return>,
+    a_done: bool,
+    b_done: bool,
+    a_next: bool,
+}
+
+/// Interleaves two Senerators that yield the same type, yielding `a0, b0, a1, b1, ...`.
+/// Once either one returns, the yielded items of the other one are yielded.
+/// When both returned, the new Senerator returns `(a's return, b's return)`.
+#[inline]
+pub fn interleave<A, B>(a: A, b: B) -> Interleave<A, B>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+{
+    Interleave {
+        a,
+        b,
+        ret_a: None,
+        ret_b: None,
+        a_done: false,
+        b_done: false,
+        a_next: true,
+    }
+}
+
+impl<A, B> Futerator for Interleave<A, B>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+{
+    type Return = (A::Return, B::Return);
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<A, B> Senerator for Interleave<A, B>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+{
+    type Yield = A::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            if self.a_done && self.b_done {
+                return Some(State::Return((self.ret_a.take()?, self.ret_b.take()?)));
+            }
+
+            if (self.a_next && !self.a_done) || self.b_done {
+                self.a_next = false;
+                match self.a.resume_with_yield()? {
+                    State::Yield(y) => return Some(State::Yield(y)),
+                    State::Return(r) => {
+                        self.ret_a = Some(r);
+                        self.a_done = true;
+                    }
+                }
+            } else {
+                self.a_next = true;
+                match self.b.resume_with_yield()? {
+                    State::Yield(y) => return Some(State::Yield(y)),
+                    State::Return(r) => {
+                        self.ret_b = Some(r);
+                        self.b_done = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Merges the yielded items of two Senerators, and returns both their returned items.
+/// Created by [`merge_by`](fn.merge_by.html).
+pub struct MergeBy<A: Senerator, B: Senerator, F> {
+    a: A,
+    b: B,
+    cmp: F,
+    head_a: Option<A::Yield>,
+    head_b: Option<B::Yield>,
+    ret_a: Option<A::Return>,
+    ret_b: Option<B::Return>,
+    a_done: bool,
+    b_done: bool,
+}
+
+/// Merges two Senerators that yield the same type, using `cmp` to decide which yielded item goes first.
+/// `cmp` is called with the next yielded item of `a` and of `b`, and should return true if the item of `a` goes first.
+/// If both Senerators yield in sorted order, the merged items are sorted as well.
+/// When both returned, the new Senerator returns `(a's return, b's return)`.
+#[inline]
+pub fn merge_by<A, B, F>(a: A, b: B, cmp: F) -> MergeBy<A, B, F>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+    F: FnMut(&A::Yield, &A::Yield) -> bool,
+{
+    MergeBy {
+        a,
+        b,
+        cmp,
+        head_a: None,
+        head_b: None,
+        ret_a: None,
+        ret_b: None,
+        a_done: false,
+        b_done: false,
+    }
+}
+
+impl<A, B, F> Futerator for MergeBy<A, B, F>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+    F: FnMut(&A::Yield, &A::Yield) -> bool,
+{
+    type Return = (A::Return, B::Return);
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<A, B, F> Senerator for MergeBy<A, B, F>
+where
+    A: Senerator,
+    B: Senerator<Yield = A::Yield>,
+    F: FnMut(&A::Yield, &A::Yield) -> bool,
+{
+    type Yield = A::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        if self.head_a.is_none() && !self.a_done {
+            match self.a.resume_with_yield()? {
+                State::Yield(y) => self.head_a = Some(y),
+                State::Return(r) => {
+                    self.ret_a = Some(r);
+                    self.a_done = true;
+                }
+            }
+        }
+
+        if self.head_b.is_none() && !self.b_done {
+            match self.b.resume_with_yield()? {
+                State::Yield(y) => self.head_b = Some(y),
+                State::Return(r) => {
+                    self.ret_b = Some(r);
+                    self.b_done = true;
+                }
+            }
+        }
+
+        match (self.head_a.take(), self.head_b.take()) {
+            (Some(a), Some(b)) => {
+                if (self.cmp)(&a, &b) {
+                    self.head_b = Some(b);
+                    Some(State::Yield(a))
+                } else {
+                    self.head_a = Some(a);
+                    Some(State::Yield(b))
+                }
+            }
+            (Some(a), None) => Some(State::Yield(a)),
+            (None, Some(b)) => Some(State::Yield(b)),
+            (None, None) => Some(State::Return((self.ret_a.take()?, self.ret_b.take()?))),
+        }
+    }
+}
+
+/// Yields all the yielded items of each yielded Generator of the underlying Senerator.
+/// Created by [`flatten`](../gen/trait.Senerator.html#method.flatten).
+pub struct Flatten<G>
+where
+    G: Senerator,
+    G::Yield: Generator,
+{
+    gen: G,
+    current: Option<Callable<G::Yield>>,
+}
+
+impl<G> Flatten<G>
+where
+    G: Senerator,
+    G::Yield: Generator,
+{
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Flatten { gen, current: None }
+    }
+}
+
+impl<G> Futerator for Flatten<G>
+where
+    G: Senerator,
+    G::Yield: Generator,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Flatten<G>
+where
+    G: Senerator,
+    G::Yield: Generator,
+{
+    type Yield = <G::Yield as Generator>::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            if let Some(ref mut inner) = self.current {
+                if let Some(State::Yield(y)) = inner.resume_with_yield() {
+                    return Some(State::Yield(y));
+                }
+            }
+            self.current = None;
+
+            match self.gen.resume_with_yield()? {
+                State::Yield(g) => self.current = Some(Callable::new(g)),
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
+    }
+}
+
+/// Replays the yielded items of the underlying Senerator forever, by resuming a fresh clone each time it returns.
+/// Created by [`cycle`](../gen/trait.Senerator.html#method.cycle).
+pub struct Cycle<G> {
+    orig: G,
+    gen: G,
+    yielded: bool,
+}
+
+impl<G> Cycle<G>
+where
+    G: Clone,
+{
+    #[inline]
+    pub(crate) fn new(gen: G) -> Self {
+        Cycle {
+            orig: gen.clone(),
+            gen,
+            yielded: false,
+        }
+    }
+}
+
+impl<G> Futerator for Cycle<G>
+where
+    G: Senerator + Clone,
+{
+    type Return = G::Return;
+
+    #[inline]
+    fn resume(&mut self) -> Futor<Self::Return> {
+        discard_yield(self.resume_with_yield())
+    }
+}
+
+impl<G> Senerator for Cycle<G>
+where
+    G: Senerator + Clone,
+{
+    type Yield = G::Yield;
+
+    #[inline]
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+        loop {
+            match self.gen.resume_with_yield()? {
+                State::Yield(y) => {
+                    self.yielded = true;
+                    return Some(State::Yield(y));
+                }
+                // A round without any yields would cycle forever, so pass the return through instead.
+                State::Return(r) => {
+                    if !self.yielded {
+                        return Some(State::Return(r));
+                    }
+                    self.yielded = false;
+                    self.gen = self.orig.clone();
+                }
+            }
+        }
+    }
+}
@@ -1,5 +1,28 @@
+use std::collections::{HashMap, HashSet};
+use std::convert::TryInto;
+use std::fmt::{self, Debug, Display, Write};
+use std::hash::Hash;
+use std::iter::{self, FromIterator, Product, Sum};
+use std::marker::PhantomData;
+use std::mem;
 use std::ops::Generator;
 use std::ops::GeneratorState;
+use std::ops::Try;
+use std::sync::atomic::AtomicBool;
+use std::sync::mpsc::SyncSender;
+use std::sync::Arc;
+
+use adapters::{
+    Accumulate, Alternate, Chunks, Cycle, Dedup, Enumerate, EnumerateFrom, EveryOther, FilterYield,
+    Flatten, FlattenIter, Fuse, Guarded, Inspect, Interruptible, Intersperse, MapInPlace,
+    MapReturnWithYields, MapStateIndexed, MapYield, Peekable, RestSenerator, Scan, SinkReturn,
+    SkipWhileGen, StepBy, TakeWhileGen, TryFlattenYields, TryIntoYields, TupleWindows2, Watch,
+    Windows, WithProgress,
+};
+use iter::{ReturnIterExt, ReturnIterator, YieldIterExt, YieldIterator};
+
+/// The message terminal methods on [Senerator](trait.Senerator.html) panic with, if there is no returned item to hand back.
+const EXHAUSTED: &str = "the Senerator already has been exhausted";
 
 /// This macro is used for the implementation of the `Futerator` trait.
 /// It advances a Generator, but returning the Yield variant of [State](gen/enum.State.html), containing the Unit type if the Generator yielded.
@@ -39,44 +62,1240 @@ pub enum State<Y, R> {
     Return(R),
 }
 
-impl<Y, R: Into<Y>> Into<Option<Y>> for State<Y, R> {
+impl<Y, R> State<Y, R> {
+    /// Maps the yielded value with `f`, passing a returned value through untouched.
+    #[inline]
+    pub fn map_yield<F, B>(self, f: F) -> State<B, R>
+    where
+        F: FnOnce(Y) -> B,
+    {
+        match self {
+            State::Yield(y) => State::Yield(f(y)),
+            State::Return(r) => State::Return(r),
+        }
+    }
+
+    /// Maps the returned value with `f`, passing a yielded value through untouched.
+    #[inline]
+    pub fn map_return<F, B>(self, f: F) -> State<Y, B>
+    where
+        F: FnOnce(R) -> B,
+    {
+        match self {
+            State::Yield(y) => State::Yield(y),
+            State::Return(r) => State::Return(f(r)),
+        }
+    }
+
+    /// Converts from `&State<Y, R>` to `State<&Y, &R>`.
+    #[inline]
+    pub fn as_ref(&self) -> State<&Y, &R> {
+        match *self {
+            State::Yield(ref y) => State::Yield(y),
+            State::Return(ref r) => State::Return(r),
+        }
+    }
+
+    /// Converts from `&mut State<Y, R>` to `State<&mut Y, &mut R>`.
+    #[inline]
+    pub fn as_mut(&mut self) -> State<&mut Y, &mut R> {
+        match *self {
+            State::Yield(ref mut y) => State::Yield(y),
+            State::Return(ref mut r) => State::Return(r),
+        }
+    }
+
+    /// Returns true if this is the Yield variant.
+    #[inline]
+    pub fn is_yield(&self) -> bool {
+        match *self {
+            State::Yield(_) => true,
+            State::Return(_) => false,
+        }
+    }
+
+    /// Returns true if this is the Return variant.
+    #[inline]
+    pub fn is_return(&self) -> bool {
+        !self.is_yield()
+    }
+
+    /// Returns the yielded value, or None if this is the Return variant.
+    #[inline]
+    pub fn yielded(self) -> Option<Y> {
+        match self {
+            State::Yield(y) => Some(y),
+            State::Return(_) => None,
+        }
+    }
+
+    /// Returns the returned value, or None if this is the Yield variant.
+    #[inline]
+    pub fn returned(self) -> Option<R> {
+        match self {
+            State::Yield(_) => None,
+            State::Return(r) => Some(r),
+        }
+    }
+
+    /// Returns the yielded value.
+    /// Panics if this is the Return variant.
+    #[inline]
+    pub fn unwrap_yield(self) -> Y {
+        match self {
+            State::Yield(y) => y,
+            State::Return(_) => panic!("called unwrap_yield on a Return"),
+        }
+    }
+
+    /// Returns the returned value.
+    /// Panics if this is the Yield variant.
+    #[inline]
+    pub fn unwrap_return(self) -> R {
+        match self {
+            State::Yield(_) => panic!("called unwrap_return on a Yield"),
+            State::Return(r) => r,
+        }
+    }
+
+    /// Converts `self` into a Result, mapping the Yield variant to `Ok`, and the Return variant to `Err`.
+    /// A returned value is what ends a Generator, so it takes the place of the value `?` short-circuits on.
+    #[inline]
+    pub fn into_result(self) -> Result<Y, R> {
+        match self {
+            State::Yield(y) => Ok(y),
+            State::Return(r) => Err(r),
+        }
+    }
+}
+
+/// The inverse of [`into_result`](enum.State.html#method.into_result): `Ok` becomes the Yield variant, and `Err` the Return variant.
+impl<Y, R> From<Result<Y, R>> for State<Y, R> {
+    #[inline]
+    fn from(result: Result<Y, R>) -> Self {
+        match result {
+            Ok(y) => State::Yield(y),
+            Err(r) => State::Return(r),
+        }
+    }
+}
+
+/// Using `?` on a State evaluates to the yielded value, or returns early with the returned value.
+/// The function the `?` is used in should return a `State<_, R>` (or any other `Try` type with an `Error` that `R` converts into),
+/// so the returned value ends up in its Return variant.
+impl<Y, R> Try for State<Y, R> {
+    type Ok = Y;
+    type Error = R;
+
+    #[inline]
+    fn into_result(self) -> Result<Y, R> {
+        State::into_result(self)
+    }
+
+    #[inline]
+    fn from_error(r: R) -> Self {
+        State::Return(r)
+    }
+
+    #[inline]
+    fn from_ok(y: Y) -> Self {
+        State::Yield(y)
+    }
+}
+
+impl<Y, R: Into<Y>> Into<Option<Y>> for State<Y, R> {
+    #[inline]
+    fn into(self) -> Option<Y> {
+        match self {
+            State::Yield(value) => Some(value),
+            State::Return(value) => Some(value.into()),
+        }
+    }
+}
+
+/// Summary statistics of the yielded items of a Senerator.
+/// Computed by [`running_stats`](trait.Senerator.html#method.running_stats).
+/// `mean`, `min` and `max` are None if nothing was yielded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub count: usize,
+    pub sum: f64,
+    pub mean: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+}
+
+/// The pairs, the trailing item that could not be paired, and the returned item.
+/// Collected by [`collect_pairs`](trait.Senerator.html#method.collect_pairs).
+pub type Pairs<Y, R> = (Vec<(Y, Y)>, Option<Y>, R);
+
+/// The smallest and the largest yielded item (None if nothing was yielded), and the returned item.
+/// Computed by [`minmax_yields`](trait.Senerator.html#method.minmax_yields).
+pub type MinMax<Y, R> = (Option<(Y, Y)>, R);
+
+/// Future Generator type.
+pub type Futor<R> = Option<State<(), R>>;
+
+/// A `Future` generator. Resolves to 1 final value, but can be 'called' multiple times to advance the underlying Generator.
+/// Returns the Yield variant of [State](enum.State.html) containing a `()`, to indicate the Generator has yielded.
+/// Only returns a Return<R> if the Generator has returned.
+/// Any further calls to [`resume`](trait.Futerator.html#method.resume) should return None.
+pub trait Futerator {
+    type Return;
+
+    fn resume(&mut self) -> Futor<Self::Return>;
+}
+
+/// Streaming Generator type.
+pub type Senor<Y, R> = Option<State<Y, R>>;
+
+/// A `Stream` generator. On each call, the generator advances, and is supposed to yield a usable item.
+/// Returns the Yield variant of [State](enum.State.html) with the yielded items of the Generator,
+/// and the Return variant of [State](enum.State.html) when the Generator returns, with the returned item.
+/// Any further calls to [`resume_with_yield`](trait.Senerator.html#method.resume_with_yield) should return None.
+pub trait Senerator: Futerator {
+    type Yield;
+
+    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return>;
+
+    /// Pairs each yielded item with its index, where the first yielded item gets index `start`.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn enumerate_yields_from(self, start: usize) -> EnumerateFrom<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateFrom::new(self, start)
+    }
+
+    /// Creates a Senerator that can peek at the next state, without consuming it.
+    #[inline]
+    fn peekable(self) -> Peekable<Self>
+    where
+        Self: Sized,
+    {
+        Peekable::new(self)
+    }
+
+    /// Creates a Senerator that is guaranteed to return None once `Self` has returned,
+    /// without resuming `Self` ever again. This also guards Senerators like `&mut G` that forward every resume.
+    #[inline]
+    fn fuse(self) -> Fuse<Self>
+    where
+        Self: Sized,
+    {
+        Fuse::new(self)
+    }
+
+    /// Drives `Self` to completion, calling the closure with a mutable reference to `state` and each yielded item.
+    /// Returns the final state, together with the returned item.
+    /// The returned item is None if `Self` already has been exhausted.
+    #[inline]
+    fn fold_state<St, F>(&mut self, mut state: St, mut f: F) -> (St, Option<Self::Return>)
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Yield),
+    {
+        loop {
+            match self.resume_with_yield() {
+                Some(State::Yield(y)) => f(&mut state, y),
+                Some(State::Return(r)) => return (state, Some(r)),
+                None => return (state, None),
+            }
+        }
+    }
+
+    /// Drives `Self` to completion, collecting each yielded item together with its index into a Vec.
+    /// Returns the Vec, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn indexed_collect(mut self) -> (Vec<(usize, Self::Yield)>, Self::Return)
+    where
+        Self: Sized,
+    {
+        let (collected, ret) = self.fold_state(Vec::new(), |collected, y| {
+            let index = collected.len();
+            collected.push((index, y));
+        });
+
+        (collected, ret.expect(EXHAUSTED))
+    }
+
+    /// Resumes `Self` once, returning the yielded item.
+    /// Returns `default` if `Self` returned instead, throwing the returned item away, or if `Self` already has been exhausted.
+    #[inline]
+    fn next_yield_or(&mut self, default: Self::Yield) -> Self::Yield {
+        match self.resume_with_yield() {
+            Some(State::Yield(y)) => y,
+            _ => default,
+        }
+    }
+
+    /// Resumes `Self` once, returning the yielded item.
+    /// Calls the closure if `Self` returned instead, throwing the returned item away, or if `Self` already has been exhausted.
+    #[inline]
+    fn next_yield_or_else<F>(&mut self, f: F) -> Self::Yield
+    where
+        Self: Sized,
+        F: FnOnce() -> Self::Yield,
+    {
+        match self.resume_with_yield() {
+            Some(State::Yield(y)) => y,
+            _ => f(),
+        }
+    }
+
+    /// Yields overlapping pairs of the yielded items, `(previous, current)`.
+    /// The first pair is yielded once two items have been yielded. The returned item is passed through untouched.
+    #[inline]
+    fn tuple_windows_2(self) -> TupleWindows2<Self>
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+    {
+        TupleWindows2::new(self)
+    }
+
+    /// Resumes `Self` `n` times, collecting exactly `n` yielded items into a Vec.
+    /// Returns None if `Self` returns, or already has been exhausted, before `n` items were yielded.
+    /// In that case the items that were yielded, and the returned item, are thrown away.
+    #[inline]
+    fn collect_exact(&mut self, n: usize) -> Option<Vec<Self::Yield>> {
+        let mut collected = Vec::with_capacity(n);
+
+        while collected.len() < n {
+            match self.resume_with_yield() {
+                Some(State::Yield(y)) => collected.push(y),
+                _ => return None,
+            }
+        }
+
+        Some(collected)
+    }
+
+    /// Takes off the first yielded item, and returns it together with the rest of `Self`.
+    /// The first item is None if `Self` returned instead, in which case the rest only returns the returned item.
+    #[inline]
+    fn split_first_yield(mut self) -> (Option<Self::Yield>, RestSenerator<Self>)
+    where
+        Self: Sized,
+    {
+        match self.resume_with_yield() {
+            Some(State::Yield(y)) => (Some(y), RestSenerator::new(self, None)),
+            Some(State::Return(r)) => (None, RestSenerator::new(self, Some(r))),
+            None => (None, RestSenerator::new(self, None)),
+        }
+    }
+
+    /// Yields the running accumulation of the yielded items.
+    /// The first yielded item is yielded as is, after that the closure is called with the accumulation so far and the next yielded item.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn accumulate_yields<F>(self, f: F) -> Accumulate<Self, F>
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+        F: FnMut(Self::Yield, Self::Yield) -> Self::Yield,
+    {
+        Accumulate::new(self, f)
+    }
+
+    /// Converts each yielded item into `T` using `TryInto`, yielding the result of the conversion.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn try_into_yields<T>(self) -> TryIntoYields<Self, T>
+    where
+        Self: Sized,
+        Self::Yield: TryInto<T>,
+    {
+        TryIntoYields::new(self)
+    }
+
+    /// Creates a Futerator that folds the yielded items into an accumulator, starting with `init`.
+    /// The Futerator yields `()` for each folded item, and returns the final accumulator.
+    /// The returned item of `Self` is thrown away.
+    #[inline]
+    fn sink_into_return<R2, F>(self, init: R2, f: F) -> SinkReturn<Self, R2, F>
+    where
+        Self: Sized,
+        F: FnMut(R2, Self::Yield) -> R2,
+    {
+        SinkReturn::new(self, init, f)
+    }
+
+    /// Flattens yielded Iterators, yielding all items of a yielded Iterator before resuming `Self` again.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn flatten_iter(self) -> FlattenIter<Self>
+    where
+        Self: Sized,
+        Self::Yield: IntoIterator,
+    {
+        FlattenIter::new(self)
+    }
+
+    /// Resumes `Self` at most `max_steps` times.
+    /// Returns the returned item if `Self` returned within those steps, throwing the yielded items away.
+    /// Otherwise the yielded items are returned as an error, and `Self` can be resumed again later on.
+    /// An exhausted `Self` also results in an error.
+    #[inline]
+    fn drive_limited(&mut self, max_steps: usize) -> Result<Self::Return, Vec<Self::Yield>> {
+        let mut collected = Vec::new();
+
+        for _ in 0..max_steps {
+            match self.resume_with_yield() {
+                Some(State::Yield(y)) => collected.push(y),
+                Some(State::Return(r)) => return Ok(r),
+                None => break,
+            }
+        }
+
+        Err(collected)
+    }
+
+    /// Maps each state of `Self` using the closure, which also gets passed the index of the step.
+    /// Every step is counted, including the one that returns.
+    /// Once the closure maps a state to the Return variant, the new Senerator is done, and never resumes `Self` again.
+    #[inline]
+    fn map_state_indexed<F, Y2, R2>(self, f: F) -> MapStateIndexed<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, State<Self::Yield, Self::Return>) -> State<Y2, R2>,
+    {
+        MapStateIndexed::new(self, f)
+    }
+
+    /// Drives `Self` to completion, collecting adjacent yielded items into non-overlapping pairs.
+    /// Returns the pairs, the trailing item that could not be paired (if any), and the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn collect_pairs(mut self) -> Pairs<Self::Yield, Self::Return>
+    where
+        Self: Sized,
+    {
+        let ((pairs, leftover), ret) = self.fold_state(
+            (Vec::new(), None),
+            |&mut (ref mut pairs, ref mut leftover), y| match leftover.take() {
+                Some(first) => pairs.push((first, y)),
+                None => *leftover = Some(y),
+            },
+        );
+
+        (pairs, leftover, ret.expect(EXHAUSTED))
+    }
+
+    /// Calls the closure on every step of `Self`, including the one that returns, passing everything through untouched.
+    /// This is meant for side effects that have to be observed from the outside, like updating a shared `AtomicUsize` or notifying a channel.
+    #[inline]
+    fn watch<F>(self, on_step: F) -> Watch<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&State<Self::Yield, Self::Return>),
+    {
+        Watch::new(self, on_step)
+    }
+
+    /// Collects the first `n` yielded items into a Vec, and returns it together with the rest of `Self`.
+    /// If `Self` returns before `n` items were yielded, the rest only returns the returned item.
+    /// The rest is the same [RestSenerator](../adapters/struct.RestSenerator.html) that [`split_first_yield`](trait.Senerator.html#method.split_first_yield) hands back,
+    /// as both only need to replay a returned item that was hit while taking items off.
+    #[inline]
+    fn split_at(mut self, n: usize) -> (Vec<Self::Yield>, RestSenerator<Self>)
+    where
+        Self: Sized,
+    {
+        let mut collected = Vec::with_capacity(n);
+
+        while collected.len() < n {
+            match self.resume_with_yield() {
+                Some(State::Yield(y)) => collected.push(y),
+                Some(State::Return(r)) => return (collected, RestSenerator::new(self, Some(r))),
+                None => break,
+            }
+        }
+
+        (collected, RestSenerator::new(self, None))
+    }
+
+    /// Maps the returned item using what has been yielded, without buffering the yielded items.
+    /// The closure `f` folds over a reference to each yielded item, starting with `init`, and the yielded items are passed through.
+    /// Once `Self` returns, `finish` is called with the final state and the returned item.
+    #[inline]
+    fn map_return_with_yields<St, F, Fin, R2>(
+        self,
+        init: St,
+        f: F,
+        finish: Fin,
+    ) -> MapReturnWithYields<Self, St, F, Fin>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, &Self::Yield),
+        Fin: FnOnce(St, Self::Return) -> R2,
+    {
+        MapReturnWithYields::new(self, init, f, finish)
+    }
+
+    /// Drives `Self` to completion, sending each yielded item over the bounded channel, and returns the returned item.
+    /// Sending blocks while the channel is full, so a slow receiver slows down `Self`.
+    /// If the receiver hung up, `Self` is still driven to completion, but the yielded items are thrown away.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn send_all_sync(mut self, tx: SyncSender<Self::Yield>) -> Self::Return
+    where
+        Self: Sized,
+    {
+        let (_, ret) = self.fold_state(tx, |tx, y| {
+            let _ = tx.send(y);
+        });
+
+        ret.expect(EXHAUSTED)
+    }
+
+    /// Drives `Self` to completion, collecting the yielded items, for a `Self` that returns a Result.
+    /// Returns the collection together with the value of `Ok`, or the error if `Self` returned an `Err`, in which case the collection is thrown away.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn collect_on_ok<C, T, E>(mut self) -> Result<(C, T), E>
+    where
+        Self: Sized + Senerator<Return = Result<T, E>>,
+        C: Default + Extend<Self::Yield>,
+    {
+        let (collected, ret) =
+            self.fold_state(C::default(), |collected, y| collected.extend(Some(y)));
+
+        ret.expect(EXHAUSTED).map(|t| (collected, t))
+    }
+
+    /// Drives a chain that surfaces its boundaries to completion, splitting it up into one segment per stage.
+    /// Such a chain yields the Yield variant of [State](enum.State.html) for items, and the Return variant of [State](enum.State.html) when a stage returned.
+    /// Each segment holds the yielded items of a stage together with its returned item, the last segment ends with the returned item of `Self`.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn segments<Y, R>(mut self) -> Vec<(Vec<Y>, R)>
+    where
+        Self: Sized + Senerator<Yield = State<Y, R>, Return = R>,
+    {
+        let ((mut segments, current), ret) = self.fold_state(
+            (Vec::new(), Vec::new()),
+            |&mut (ref mut segments, ref mut current), state| match state {
+                State::Yield(y) => current.push(y),
+                State::Return(r) => segments.push((mem::replace(current, Vec::new()), r)),
+            },
+        );
+
+        segments.push((current, ret.expect(EXHAUSTED)));
+        segments
+    }
+
+    /// Calls the closure with a mutable reference to each yielded item, before yielding it.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn map_in_place<F>(self, f: F) -> MapInPlace<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Yield),
+    {
+        MapInPlace::new(self, f)
+    }
+
+    /// Creates a Senerator that can be [poisoned](../adapters/struct.Guarded.html#method.poison),
+    /// so external logic can abort `Self` safely.
+    #[inline]
+    fn guarded(self) -> Guarded<Self>
+    where
+        Self: Sized,
+    {
+        Guarded::new(self)
+    }
+
+    /// Drives `Self` to completion, collecting only the first occurrence of each distinct yielded item.
+    /// Returns the collection, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn collect_unique<C>(mut self) -> (C, Self::Return)
+    where
+        Self: Sized,
+        Self::Yield: Hash + Eq + Clone,
+        C: Default + Extend<Self::Yield>,
+    {
+        let ((collected, _), ret) = self.fold_state(
+            (C::default(), HashSet::new()),
+            |&mut (ref mut collected, ref mut seen), y| {
+                if seen.insert(y.clone()) {
+                    collected.extend(Some(y));
+                }
+            },
+        );
+
+        (collected, ret.expect(EXHAUSTED))
+    }
+
+    /// Creates a Senerator that checks `flag` before each resume.
+    /// Once `flag` is set, it returns None as if it was exhausted, without resuming `Self`.
+    /// This allows cancelling `Self` from another thread.
+    #[inline]
+    fn interruptible(self, flag: Arc<AtomicBool>) -> Interruptible<Self>
+    where
+        Self: Sized,
+    {
+        Interruptible::new(self, flag)
+    }
+
+    /// Flattens yielded `Ok` Iterators into their items, each yielded as `Ok`. Yielded errors are yielded as a single `Err`.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn try_flatten_yields<I, E>(self) -> TryFlattenYields<Self, I>
+    where
+        Self: Sized + Senerator<Yield = Result<I, E>>,
+        I: IntoIterator,
+    {
+        TryFlattenYields::new(self)
+    }
+
+    /// Drives `Self` to completion, keeping track of the smallest and the largest yielded item.
+    /// Returns `(min, max)`, or None if nothing was yielded, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn minmax_yields(mut self) -> MinMax<Self::Yield, Self::Return>
+    where
+        Self: Sized,
+        Self::Yield: Ord + Clone,
+    {
+        let (minmax, ret) = self.fold_state(None, |minmax, y| {
+            *minmax = match minmax.take() {
+                Some((min, max)) => {
+                    if y < min {
+                        Some((y, max))
+                    } else if y > max {
+                        Some((min, y))
+                    } else {
+                        Some((min, max))
+                    }
+                }
+                None => Some((y.clone(), y)),
+            };
+        });
+
+        (minmax, ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, keeping only the first and the last yielded item.
+    /// Both are None if nothing was yielded, and equal if exactly one item was yielded.
+    /// Returns them together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn first_last(mut self) -> (Option<Self::Yield>, Option<Self::Yield>, Self::Return)
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+    {
+        let ((first, last), ret) =
+            self.fold_state((None, None), |&mut (ref mut first, ref mut last), y| {
+                if first.is_none() {
+                    *first = Some(y.clone());
+                }
+                *last = Some(y);
+            });
+
+        (first, last, ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, grouping the values of yielded `(key, value)` pairs by their key.
+    /// Returns the groups, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn group_into_map<K, V>(mut self) -> (HashMap<K, Vec<V>>, Self::Return)
+    where
+        Self: Sized + Senerator<Yield = (K, V)>,
+        K: Hash + Eq,
+    {
+        let (groups, ret) = self.fold_state(HashMap::new(), |groups, (k, v)| {
+            groups.entry(k).or_insert_with(Vec::new).push(v);
+        });
+
+        (groups, ret.expect(EXHAUSTED))
+    }
+
+    /// Yields `filler` before the first yielded item, between every two yielded items, and after the last yielded item.
+    /// If nothing is yielded, `filler` is yielded once. The returned item is passed through untouched.
+    #[inline]
+    fn alternate(self, filler: Self::Yield) -> Alternate<Self>
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+    {
+        Alternate::new(self, filler)
+    }
+
+    /// Drives `Self` to completion, collecting the yielded items into a boxed slice.
+    /// Returns the boxed slice, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn collect_boxed(mut self) -> (Box<[Self::Yield]>, Self::Return)
+    where
+        Self: Sized,
+    {
+        let (collected, ret) = self.fold_state(Vec::new(), |collected, y| collected.push(y));
+
+        (collected.into_boxed_slice(), ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, for a `Self` that yields Results.
+    /// Collects the values of yielded `Ok`s into one collection, and yielded errors into another.
+    /// Returns both collections, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn partition_results<C, EC, T, E>(mut self) -> (C, EC, Self::Return)
+    where
+        Self: Sized + Senerator<Yield = Result<T, E>>,
+        C: Default + Extend<T>,
+        EC: Default + Extend<E>,
+    {
+        let ((oks, errs), ret) = self.fold_state(
+            (C::default(), EC::default()),
+            |&mut (ref mut oks, ref mut errs), y| match y {
+                Ok(t) => oks.extend(Some(t)),
+                Err(e) => errs.extend(Some(e)),
+            },
+        );
+
+        (oks, errs, ret.expect(EXHAUSTED))
+    }
+
+    /// Calls the closure with `(completed, total)` after each yielded item, where `completed` is the number of items yielded so far.
+    /// Everything is passed through untouched.
+    #[inline]
+    fn with_progress<F>(self, total: usize, on_progress: F) -> WithProgress<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(usize, usize),
+    {
+        WithProgress::new(self, total, on_progress)
+    }
+
+    /// Drives `Self` to completion, counting how often each distinct item was yielded.
+    /// Returns the counts, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn histogram(mut self) -> (HashMap<Self::Yield, usize>, Self::Return)
+    where
+        Self: Sized,
+        Self::Yield: Hash + Eq,
+    {
+        let (histogram, ret) = self.fold_state(HashMap::new(), |histogram, y| {
+            *histogram.entry(y).or_insert(0) += 1;
+        });
+
+        (histogram, ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, collecting the indices of the yielded items that pass the predicate.
+    /// The first yielded item has index 0. Returns the indices, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn positions<P>(mut self, mut predicate: P) -> (Vec<usize>, Self::Return)
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        let ((positions, _), ret) = self.fold_state(
+            (Vec::new(), 0),
+            |&mut (ref mut positions, ref mut index), y| {
+                if predicate(&y) {
+                    positions.push(*index);
+                }
+                *index += 1;
+            },
+        );
+
+        (positions, ret.expect(EXHAUSTED))
+    }
+
+    /// Yields every other yielded item. If `keep_first` is true, the items at even indices are yielded, otherwise the items at odd indices.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn every_other(self, keep_first: bool) -> EveryOther<Self>
+    where
+        Self: Sized,
+    {
+        EveryOther::new(self, keep_first)
+    }
+
+    /// Drives `Self` to completion, computing the [summary statistics](struct.Stats.html) of the yielded items.
+    /// Returns the statistics, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn running_stats(mut self) -> (Stats, Self::Return)
+    where
+        Self: Sized,
+        Self::Yield: Into<f64>,
+    {
+        let init = Stats {
+            count: 0,
+            sum: 0.0,
+            mean: None,
+            min: None,
+            max: None,
+        };
+
+        let (mut stats, ret) = self.fold_state(init, |stats, y| {
+            let y = y.into();
+            stats.count += 1;
+            stats.sum += y;
+            stats.min = Some(stats.min.map_or(y, |min| min.min(y)));
+            stats.max = Some(stats.max.map_or(y, |max| max.max(y)));
+        });
+
+        if stats.count > 0 {
+            stats.mean = Some(stats.sum / stats.count as f64);
+        }
+
+        (stats, ret.expect(EXHAUSTED))
+    }
+
+    /// Maps each yielded item using the closure. The returned item is passed through untouched.
+    #[inline]
+    fn map_yield<F, B>(self, f: F) -> MapYield<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Yield) -> B,
+    {
+        MapYield::new(self, f)
+    }
+
+    /// Yields only the yielded items that pass the predicate. The returned item is always passed through.
+    #[inline]
+    fn filter_yield<P>(self, predicate: P) -> FilterYield<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        FilterYield::new(self, predicate)
+    }
+
+    /// Yields the yielded items while they pass the predicate.
+    /// Once an item fails the predicate, `Self` is driven to completion, throwing away the rest of the yielded items, and the returned item is returned.
+    /// The predicate is never called on the returned item.
+    #[inline]
+    fn take_while<P>(self, predicate: P) -> TakeWhileGen<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        TakeWhileGen::new(self, predicate)
+    }
+
+    /// Skips the leading yielded items while they pass the predicate, after that all yielded items, and the returned item are passed through.
+    /// Once an item fails the predicate, the predicate is never called again.
+    #[inline]
+    fn skip_while<P>(self, predicate: P) -> SkipWhileGen<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        SkipWhileGen::new(self, predicate)
+    }
+
+    /// Pairs each yielded item with its index, starting at 0. The index only advances on yielded items.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized,
+    {
+        self.enumerate_yields_from(0)
+    }
+
+    /// Calls the closure with a reference to each yielded item, before passing it on. Useful for debugging.
+    /// The closure is never called with the returned item.
+    #[inline]
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Yield),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Calls the closure with a mutable reference to `initial` and each yielded item, yielding what the closure returns.
+    /// Once the closure returns None, nothing is yielded anymore: `Self` is driven to completion, throwing away the rest of the yielded items,
+    /// and the returned item is returned. The closure is never called again after it returned None.
+    #[inline]
+    fn scan<St, B, F>(self, initial: St, f: F) -> Scan<Self, St, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut St, Self::Yield) -> Option<B>,
+    {
+        Scan::new(self, initial, f)
+    }
+
+    /// Yields the first yielded item, and then every `step`th yielded item, throwing away the items in between.
+    /// The returned item is never skipped.
+    /// Panics if `step` is 0.
+    #[inline]
+    fn step_by(self, step: usize) -> StepBy<Self>
+    where
+        Self: Sized,
+    {
+        StepBy::new(self, step)
+    }
+
+    /// Yields the yielded items in chunks of `size` items.
+    /// When `Self` returns, the last chunk may hold less than `size` items, and is yielded before the returned item is returned.
+    /// Panics if `size` is 0.
+    #[inline]
+    fn chunks(self, size: usize) -> Chunks<Self>
+    where
+        Self: Sized,
+    {
+        Chunks::new(self, size)
+    }
+
+    /// Yields overlapping windows of `size` yielded items, sliding one item at a time.
+    /// If `Self` returns before `size` items were yielded, no window is yielded. The returned item is passed through untouched.
+    /// Panics if `size` is 0.
+    #[inline]
+    fn windows(self, size: usize) -> Windows<Self>
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+    {
+        Windows::new(self, size)
+    }
+
+    /// Yields `sep` between every two yielded items, but not before the first or after the last one.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn intersperse(self, sep: Self::Yield) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Yield: Clone,
+    {
+        Intersperse::new(self, sep)
+    }
+
+    /// Collapses consecutive equal yielded items into one. Equal items that are not next to each other are all yielded.
+    /// The returned item is passed through untouched.
+    #[inline]
+    fn dedup(self) -> Dedup<Self>
+    where
+        Self: Sized,
+        Self::Yield: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Flattens yielded Generators, yielding all the yielded items of a yielded Generator before resuming `Self` again.
+    /// The returned items of the yielded Generators are thrown away, the returned item of `Self` is passed through untouched.
+    #[inline]
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Yield: Generator,
+    {
+        Flatten::new(self)
+    }
+
+    /// Replays the yielded items of `Self` forever, by starting over from a clone of the original each time it returns.
+    /// The returned items in between the rounds are thrown away. The new Senerator is infinite, so bound it before draining it.
+    /// Only if `Self` returns without yielding anything at all, the returned item is passed through.
+    #[inline]
+    fn cycle(self) -> Cycle<Self>
+    where
+        Self: Sized + Clone,
+    {
+        Cycle::new(self)
+    }
+
+    /// Drives `Self` to completion, collecting all yielded items into any collection that can be built from an Iterator.
+    /// Returns the collection, together with the returned item. Unlike [`iter_all`](../iter/trait.ReturnIterExt.html#tymethod.iter_all), this does not require `Self::Return: Into<Self::Yield>`.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn collect_with_return<C>(mut self) -> (C, Self::Return)
+    where
+        Self: Sized,
+        C: FromIterator<Self::Yield>,
+    {
+        let mut ret = None;
+
+        let collected = iter::repeat(())
+            .scan(&mut self, |gen, ()| match gen.resume_with_yield()? {
+                State::Yield(y) => Some(y),
+                State::Return(r) => {
+                    ret = Some(r);
+                    None
+                }
+            })
+            .collect();
+
+        (collected, ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, folding all yielded items into an accumulator.
+    /// Returns the accumulator, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn fold_all<B, F>(mut self, init: B, mut f: F) -> (B, Self::Return)
+    where
+        Self: Sized,
+        F: FnMut(B, Self::Yield) -> B,
+    {
+        // The accumulator is taken out for every call to the closure, and always put back.
+        let (acc, ret) = self.fold_state(Some(init), |acc, y| {
+            *acc = acc.take().map(|acc| f(acc, y));
+        });
+
+        (acc.unwrap(), ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, calling the closure with each yielded item.
+    /// Returns the returned item, the closure is never called with it.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn for_each_yield<F>(mut self, mut f: F) -> Self::Return
+    where
+        Self: Sized,
+        F: FnMut(Self::Yield),
+    {
+        let ((), ret) = self.fold_state((), |_, y| f(y));
+        ret.expect(EXHAUSTED)
+    }
+
+    /// Drives `Self` to completion, counting the yielded items.
+    /// Returns the count, together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn count_yields(mut self) -> (usize, Self::Return)
+    where
+        Self: Sized,
+    {
+        let (count, ret) = self.fold_state(0, |count, _| *count += 1);
+        (count, ret.expect(EXHAUSTED))
+    }
+
+    /// Resumes `Self` until the `n`th yielded item (counting from 0), throwing away all yielded items before it.
+    /// Returns None if `Self` returned before that, throwing the returned item away, or if `Self` already has been exhausted.
+    /// `Self` is only borrowed, so it can be resumed further afterwards.
+    #[inline]
+    fn nth_yield(&mut self, mut n: usize) -> Option<Self::Yield> {
+        loop {
+            match self.resume_with_yield()? {
+                State::Yield(y) => {
+                    if n == 0 {
+                        return Some(y);
+                    }
+                    n -= 1;
+                }
+                State::Return(_) => return None,
+            }
+        }
+    }
+
+    /// Resumes `Self` until a yielded item passes the predicate, and returns that item.
+    /// Returns None if `Self` returned before that, throwing the returned item away, or if `Self` already has been exhausted.
+    /// `Self` is only borrowed, so it can be resumed further afterwards.
+    #[inline]
+    fn find_yield<P>(&mut self, mut predicate: P) -> Option<Self::Yield>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        loop {
+            match self.resume_with_yield()? {
+                State::Yield(y) => {
+                    if predicate(&y) {
+                        return Some(y);
+                    }
+                }
+                State::Return(_) => return None,
+            }
+        }
+    }
+
+    /// Resumes `Self` until a yielded item passes the predicate, and returns the index of that item.
+    /// The index counts from the first item yielded during this call.
+    /// Returns None if `Self` returned before that, throwing the returned item away, or if `Self` already has been exhausted.
+    /// `Self` is only borrowed, so it can be resumed further afterwards.
+    #[inline]
+    fn position_yield<P>(&mut self, mut predicate: P) -> Option<usize>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        let mut index = 0;
+        self.find_yield(|y| {
+            if predicate(y) {
+                return true;
+            }
+            index += 1;
+            false
+        })?;
+        Some(index)
+    }
+
+    /// Resumes `Self` until a yielded item passes the predicate, and returns true if one did.
+    /// Returns false if `Self` returned before that, throwing the returned item away, or if `Self` already has been exhausted.
+    #[inline]
+    fn any_yield<P>(&mut self, predicate: P) -> bool
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        self.find_yield(predicate).is_some()
+    }
+
+    /// Resumes `Self` until a yielded item fails the predicate, and returns false if one did.
+    /// Returns true if `Self` returned before that, throwing the returned item away, or if `Self` already has been exhausted.
+    #[inline]
+    fn all_yield<P>(&mut self, mut predicate: P) -> bool
+    where
+        Self: Sized,
+        P: FnMut(&Self::Yield) -> bool,
+    {
+        self.find_yield(|y| !predicate(y)).is_none()
+    }
+
+    /// Drives `Self` to completion, keeping the yielded item that gives the maximum value from the closure.
+    /// Like `Iterator::max_by_key`, the last one wins if several items are equally maximum.
+    /// Returns the item (None if nothing was yielded), together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn max_by_key<B, F>(mut self, mut f: F) -> (Option<Self::Yield>, Self::Return)
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Yield) -> B,
+    {
+        let (max, ret) = self.fold_state(None, |max: &mut Option<(B, Self::Yield)>, y| {
+            let key = f(&y);
+            let replace = match *max {
+                Some((ref max_key, _)) => key >= *max_key,
+                None => true,
+            };
+            if replace {
+                *max = Some((key, y));
+            }
+        });
+
+        (max.map(|(_, y)| y), ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, keeping the yielded item that gives the minimum value from the closure.
+    /// Like `Iterator::min_by_key`, the first one wins if several items are equally minimum.
+    /// Returns the item (None if nothing was yielded), together with the returned item.
+    /// Panics if `Self` already has been exhausted.
+    #[inline]
+    fn min_by_key<B, F>(mut self, mut f: F) -> (Option<Self::Yield>, Self::Return)
+    where
+        Self: Sized,
+        B: Ord,
+        F: FnMut(&Self::Yield) -> B,
+    {
+        let (min, ret) = self.fold_state(None, |min: &mut Option<(B, Self::Yield)>, y| {
+            let key = f(&y);
+            let replace = match *min {
+                Some((ref min_key, _)) => key < *min_key,
+                None => true,
+            };
+            if replace {
+                *min = Some((key, y));
+            }
+        });
+
+        (min.map(|(_, y)| y), ret.expect(EXHAUSTED))
+    }
+
+    /// Drives `Self` to completion, summing all yielded items and the returned item.
+    /// Sums to zero if `Self` already has been exhausted.
+    #[inline]
+    fn sum_all(self) -> Self::Yield
+    where
+        Self: Sized,
+        Self::Return: Into<Self::Yield>,
+        Self::Yield: Sum,
+    {
+        self.iter_all().sum()
+    }
+
+    /// Drives `Self` to completion, multiplying all yielded items and the returned item.
+    /// Multiplies to one if `Self` already has been exhausted.
+    #[inline]
+    fn product_all(self) -> Self::Yield
+    where
+        Self: Sized,
+        Self::Return: Into<Self::Yield>,
+        Self::Yield: Product,
+    {
+        self.iter_all().product()
+    }
+
+    /// Drives `Self` to completion, keeping only the last yielded item.
+    /// Returns the item (None if nothing was yielded), together with the returned item.
+    /// Panics if `Self` already has been exhausted.
     #[inline]
-    fn into(self) -> Option<Y> {
-        match self {
-            State::Yield(value) => Some(value),
-            State::Return(value) => Some(value.into()),
-        }
+    fn last_yield(mut self) -> (Option<Self::Yield>, Self::Return)
+    where
+        Self: Sized,
+    {
+        let (last, ret) = self.fold_state(None, |last, y| *last = Some(y));
+        (last, ret.expect(EXHAUSTED))
     }
-}
 
-/// Future Generator type.
-pub type Futor<R> = Option<State<(), R>>;
+    /// Borrows `Self` mutably, so adapters can be used on the borrow while `Self` can be resumed further afterwards.
+    #[inline]
+    fn by_ref(&mut self) -> &mut Self
+    where
+        Self: Sized,
+    {
+        self
+    }
 
-/// A `Future` generator. Resolves to 1 final value, but can be 'called' multiple times to advance the underlying Generator.
-/// Returns the Yield variant of [State](enum.State.html) containing a `()`, to indicate the Generator has yielded.
-/// Only returns a Return<R> if the Generator has returned.
-/// Any further calls to [`resume`](trait.Futerator.html#method.resume) should return None.
-pub trait Futerator {
-    type Return;
+    /// Resumes `Self` up to `n` times, and returns the last state, stopping early if `Self` returned.
+    /// Returns None without resuming `Self` if `n` is 0, or if `Self` already has been exhausted.
+    #[inline]
+    fn resume_n(&mut self, n: usize) -> Senor<Self::Yield, Self::Return> {
+        let mut last = None;
 
-    fn resume(&mut self) -> Futor<Self::Return>;
-}
+        for _ in 0..n {
+            match self.resume_with_yield()? {
+                State::Yield(y) => last = Some(State::Yield(y)),
+                State::Return(r) => return Some(State::Return(r)),
+            }
+        }
 
-/// Streaming Generator type.
-pub type Senor<Y, R> = Option<State<Y, R>>;
+        last
+    }
 
-/// A `Stream` generator. On each call, the generator advances, and is supposed to yield a usable item.
-/// Returns the Yield variant of [State](enum.State.html) with the yielded items of the Generator,
-/// and the Return variant of [State](enum.State.html) when the Generator returns, with the returned item.
-/// Any further calls to [`resume_with_yield`](trait.Senerator.html#method.resume_with_yield) should return None.
-pub trait Senerator: Futerator {
-    type Yield;
+    /// Drives `Self` to completion, collecting all yielded items into a Vec, and throwing the returned item away.
+    #[inline]
+    fn drain(self) -> Vec<Self::Yield>
+    where
+        Self: Sized,
+    {
+        self.iter_yielded().collect()
+    }
 
-    fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return>;
+    /// Splits `Self` into a `Stream` of the yielded items, and a `Future` that resolves to the returned item.
+    /// Both halves share the underlying Generator, so this is single-threaded only.
+    /// The `Future` only resolves once the `Stream` has been drained.
+    #[cfg(feature = "extfutures")]
+    #[inline]
+    fn split_stream(
+        self,
+    ) -> (
+        ext_futures::YieldStream<Self>,
+        ext_futures::ReturnFuture<Self>,
+    )
+    where
+        Self: Sized,
+    {
+        ext_futures::split(self)
+    }
 }
 
 /// A safe wrapper around a Generator.
 /// Once the Generator is returned, it's guaranteed that [`resume`](https://doc.rust-lang.org/1.23.0/std/ops/trait.Generator.html#tymethod.resume) is never called again on the Generator.
+#[derive(Clone)]
 pub struct Callable<G>(Option<G>);
 
 impl<G> Callable<G> {
@@ -108,6 +1327,79 @@ impl<G> Callable<G> {
         }))
     }
 
+    /// chains a new Callable, but only if the return value of the underlying Generator passes the predicate.
+    /// If it does, this behaves like [`chain`](struct.Callable.html#method.chain),
+    /// otherwise the new generator returns the return value of the underlying Generator right away.
+    /// Returns None if the underlying Generator already has been exhausted.
+    pub fn chain_if<O>(
+        self,
+        predicate: impl FnOnce(&G::Return) -> bool,
+        g: impl FnOnce(G::Return) -> O,
+    ) -> Option<Callable<impl Generator<Yield = G::Yield, Return = G::Return>>>
+    where
+        G: Generator,
+        O: Generator<Yield = G::Yield, Return = G::Return>,
+    {
+        let mut generator = self.into_inner()?;
+
+        Some(Callable::new(move || {
+            let ret = yield_from!(generator);
+
+            if !predicate(&ret) {
+                return ret;
+            }
+
+            let mut provided_gen = g(ret);
+
+            return yield_from!(provided_gen);
+        }))
+    }
+
+    /// chains the items of an Iterator. this function takes a closure that takes the return value of the underlying Generator and returns something that can be turned into an Iterator,
+    /// The newly created Callable has a generator under the hood that first yields all the items of the old generator, once that returns it passes the returned value into the closure,
+    /// and then yields all the items of the Iterator. The new generator returns `()`.
+    /// Returns None if the underlying Generator already has been exhausted.
+    pub fn chain_return_iter<F, I>(
+        self,
+        f: F,
+    ) -> Option<Callable<impl Generator<Yield = G::Yield, Return = ()>>>
+    where
+        G: Generator,
+        F: FnOnce(G::Return) -> I,
+        I: IntoIterator<Item = G::Yield>,
+    {
+        let mut generator = self.into_inner()?;
+
+        Some(Callable::new(move || {
+            let ret = yield_from!(generator);
+
+            for item in f(ret) {
+                yield item;
+            }
+        }))
+    }
+
+    /// chains another Callable. The newly created Callable has a generator under the hood that first yields all the items of the old generator,
+    /// throws away its returned value, and then yields all the items of `other`. The new generator returns whatever `other` returns.
+    /// Returns None if either of the underlying Generators already has been exhausted.
+    pub fn chain_callable<O>(
+        self,
+        other: Callable<O>,
+    ) -> Option<Callable<impl Generator<Yield = G::Yield, Return = O::Return>>>
+    where
+        G: Generator,
+        O: Generator<Yield = G::Yield>,
+    {
+        let mut generator = self.into_inner()?;
+        let mut other = other.into_inner()?;
+
+        Some(Callable::new(move || {
+            let _ = yield_from!(generator);
+
+            return yield_from!(other);
+        }))
+    }
+
     /// Takes out the underlying Generator, and calls the closure with it. The closure should return a new Generator.
     /// Returns None if the underlying Generator already has been exhausted.
     #[inline]
@@ -172,6 +1464,31 @@ impl<G> Callable<G> {
         self.0.take()
     }
 
+    /// Puts `g` in as the new underlying Generator, returning the old one.
+    /// Returns None if the underlying Generator already has been exhausted
+    #[inline]
+    pub fn replace(&mut self, g: G) -> Option<G> {
+        self.0.replace(g)
+    }
+
+    /// Puts `g` in as the new underlying Generator, dropping the old one.
+    #[inline]
+    pub fn set(&mut self, g: G) {
+        self.0 = Some(g);
+    }
+
+    /// Returns true if the underlying Generator already has been exhausted, or was taken out.
+    #[inline]
+    pub fn is_done(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns true if the underlying Generator can still be resumed.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.0.is_some()
+    }
+
     /// Returns a mutable reference to the underlying Generator.
     #[inline]
     pub fn as_mut(&mut self) -> Option<&mut G> {
@@ -179,6 +1496,19 @@ impl<G> Callable<G> {
     }
 }
 
+impl<G> Debug for Callable<G> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let state = match self.0 {
+            Some(_) => "Active",
+            None => "Exhausted",
+        };
+
+        f.debug_struct("Callable")
+            .field("state", &format_args!("{}", state))
+            .finish()
+    }
+}
+
 impl<G> Futerator for Callable<G>
 where
     G: Generator,
@@ -231,17 +1561,283 @@ where
     }
 }
 
+/// A Generator that yields all the items of an Iterator, and returns `()` once the Iterator is exhausted.
+/// Created by [`Callable::from_iter`](struct.Callable.html#method.from_iter).
+#[derive(Debug, Clone)]
+pub struct FromIter<I>(I);
+
+impl<I> Generator for FromIter<I>
+where
+    I: Iterator,
+{
+    type Yield = I::Item;
+    type Return = ();
+
+    #[inline]
+    unsafe fn resume(&mut self) -> GeneratorState<Self::Yield, Self::Return> {
+        match self.0.next() {
+            Some(item) => GeneratorState::Yielded(item),
+            None => GeneratorState::Complete(()),
+        }
+    }
+}
+
+impl<I> Callable<FromIter<I>>
+where
+    I: Iterator,
+{
+    /// Creates a new Callable out of anything that can be turned into an Iterator.
+    /// The Callable yields all the items of the Iterator, and returns `()` once the Iterator is exhausted.
+    #[inline]
+    pub fn from_iter<T>(iter: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Callable::new(iter.into_generator())
+    }
+}
+
+/// A Senerator that knows exactly how many items it is going to yield before it returns.
+pub trait ExactSizeSenerator: Senerator {
+    /// Returns the number of items that are left to be yielded.
+    fn remaining(&self) -> usize;
+
+    /// Returns true if `Self` already has returned, or has been exhausted.
+    fn is_done(&self) -> bool;
+
+    /// Like [`iter_yielded`](../iter/trait.YieldIterExt.html#tymethod.iter_yielded), but the Iterator gives an exact size hint.
+    #[inline]
+    fn iter_yielded_exact(self) -> YieldIterator<Self>
+    where
+        Self: Sized,
+    {
+        YieldIterator::exact(self)
+    }
+
+    /// Like [`iter_all`](../iter/trait.ReturnIterExt.html#tymethod.iter_all), but the Iterator gives an exact size hint.
+    /// The returned item is counted as well, as long as it hasn't been returned yet.
+    #[inline]
+    fn iter_all_exact(self) -> ReturnIterator<Self>
+    where
+        Self: Sized,
+        Self::Return: Into<Self::Yield>,
+    {
+        ReturnIterator::exact(self)
+    }
+
+    /// Like [`drain`](trait.Senerator.html#method.drain), but the Vec is allocated up front.
+    #[inline]
+    fn drain_exact(self) -> Vec<Self::Yield>
+    where
+        Self: Sized,
+    {
+        let mut drained = Vec::with_capacity(self.remaining());
+        drained.extend(self.iter_yielded());
+        drained
+    }
+}
+
+impl<I> ExactSizeSenerator for Callable<FromIter<I>>
+where
+    I: ExactSizeIterator,
+{
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.0.as_ref().map_or(0, |generator| generator.0.len())
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        self.0.is_none()
+    }
+}
+
+impl<'a, G> ExactSizeSenerator for &'a mut G
+where
+    G: ExactSizeSenerator,
+{
+    #[inline]
+    fn remaining(&self) -> usize {
+        (**self).remaining()
+    }
+
+    #[inline]
+    fn is_done(&self) -> bool {
+        (**self).is_done()
+    }
+}
+
+/// Conversion into a Generator.
+/// This is implemented for everything that can be turned into an Iterator,
+/// so vectors, ranges and arrays can be handed to [`Callable::new`](struct.Callable.html#method.new) directly.
+///
+/// ```
+/// extern crate generator_ext;
+/// use generator_ext::gen::{Callable, IntoGenerator};
+/// use generator_ext::iter::YieldIterExt;
+///
+/// let generator = Callable::new(vec![1, 2, 3].into_generator());
+/// assert_eq!(generator.iter_yielded().collect::<Vec<i32>>(), vec![1, 2, 3]);
+/// ```
+pub trait IntoGenerator {
+    type Gen: Generator;
+
+    /// Turns `Self` into a Generator.
+    fn into_generator(self) -> Self::Gen;
+}
+
+impl<T> IntoGenerator for T
+where
+    T: IntoIterator,
+{
+    type Gen = FromIter<T::IntoIter>;
+
+    #[inline]
+    fn into_generator(self) -> Self::Gen {
+        FromIter(self.into_iter())
+    }
+}
+
+/// Creates a new Callable that yields `value` once, and then returns `()`.
+pub fn once<T>(value: T) -> Callable<impl Generator<Yield = T, Return = ()>> {
+    Callable::new(move || {
+        yield value;
+    })
+}
+
+/// A Generator that never yields, and returns the default value of `R` right away.
+struct Empty<Y, R>(PhantomData<fn() -> (Y, R)>);
+
+impl<Y, R> Generator for Empty<Y, R>
+where
+    R: Default,
+{
+    type Yield = Y;
+    type Return = R;
+
+    #[inline]
+    unsafe fn resume(&mut self) -> GeneratorState<Self::Yield, Self::Return> {
+        GeneratorState::Complete(R::default())
+    }
+}
+
+/// Creates a new Callable that yields nothing, and returns `R::default()` right away.
+pub fn empty<Y, R>() -> Callable<impl Generator<Yield = Y, Return = R>>
+where
+    R: Default,
+{
+    Callable::new(Empty(PhantomData))
+}
+
+/// Creates a new Callable that yields a clone of `value` forever.
+/// The Callable never returns, so bound it (for example with `take` on [`iter_yielded`](../iter/trait.YieldIterExt.html#tymethod.iter_yielded))
+/// before draining it, and never use it with [`iter_all`](../iter/trait.ReturnIterExt.html#tymethod.iter_all) on its own.
+pub fn repeat<T>(value: T) -> Callable<impl Generator<Yield = T, Return = ()>>
+where
+    T: Clone,
+{
+    Callable::new(move || loop {
+        yield value.clone();
+    })
+}
+
+/// Creates a new Callable that yields the result of calling `f`, every time it is resumed.
+/// Like [`repeat`](fn.repeat.html), the Callable is infinite and never returns, so bound it before draining it.
+pub fn repeat_with<T, F>(mut f: F) -> Callable<impl Generator<Yield = T, Return = ()>>
+where
+    F: FnMut() -> T,
+{
+    Callable::new(move || loop {
+        yield f();
+    })
+}
+
+/// Drives both Futerators to completion, resuming them in turns, and returns both returned items.
+/// Panics if either Futerator already has been exhausted.
+pub fn zip_returns<A, B>(mut a: A, mut b: B) -> (A::Return, B::Return)
+where
+    A: Futerator,
+    B: Futerator,
+{
+    #[inline]
+    fn step<F: Futerator>(f: &mut F, ret: &mut Option<F::Return>) {
+        if ret.is_none() {
+            if let State::Return(r) = f.resume().expect(EXHAUSTED) {
+                *ret = Some(r);
+            }
+        }
+    }
+
+    let (mut ret_a, mut ret_b) = (None, None);
+
+    while ret_a.is_none() || ret_b.is_none() {
+        step(&mut a, &mut ret_a);
+        step(&mut b, &mut ret_b);
+    }
+
+    (ret_a.unwrap(), ret_b.unwrap())
+}
+
+/// Extension methods for any type implementing [Senerator](trait.Senerator.html).
+pub trait SeneratorExt: Senerator {
+    /// Drives the underlying Generator to completion, counting the yielded items.
+    /// Both the yielded items, and the returned item are thrown away.
+    fn drain_count(self) -> usize;
+
+    /// Drives the underlying Generator to completion, rendering the yielded items into a String, separated by `sep`.
+    /// Returns the String, together with the returned item.
+    /// Panics if the underlying Generator already has been exhausted.
+    fn join_to_string(self, sep: &str) -> (String, Self::Return)
+    where
+        Self::Yield: Display;
+}
+
+impl<G> SeneratorExt for G
+where
+    G: Senerator,
+{
+    #[inline]
+    fn drain_count(mut self) -> usize {
+        let mut count = 0;
+        while let Some(State::Yield(())) = self.resume() {
+            count += 1;
+        }
+        count
+    }
+
+    #[inline]
+    fn join_to_string(mut self, sep: &str) -> (String, Self::Return)
+    where
+        Self::Yield: Display,
+    {
+        let ((joined, _), ret) = self.fold_state(
+            (String::new(), true),
+            |&mut (ref mut joined, ref mut first), y| {
+                if !*first {
+                    joined.push_str(sep);
+                }
+                *first = false;
+                let _ = write!(joined, "{}", y);
+            },
+        );
+
+        (joined, ret.expect(EXHAUSTED))
+    }
+}
+
 #[cfg(feature = "extfutures")]
 pub mod ext_futures {
 
     extern crate futures;
 
-    use self::futures::task::Context;
+    use self::futures::task::{Context, Waker};
     use self::futures::{Async, Poll};
     use self::futures::{Future, Stream};
 
     use super::{Callable, Futerator, Senerator, State};
+    use std::cell::RefCell;
     use std::ops::Generator;
+    use std::rc::Rc;
 
     impl<G: Generator> Future for Callable<G> {
         type Item = G::Return;
@@ -268,4 +1864,75 @@ pub mod ext_futures {
             }
         }
     }
+
+    /// The state shared between a [`YieldStream`](struct.YieldStream.html) and its [`ReturnFuture`](struct.ReturnFuture.html).
+    struct Split<G: Senerator> {
+        gen: G,
+        ret: Option<G::Return>,
+        drained: bool,
+        waker: Option<Waker>,
+    }
+
+    /// The `Stream` half of [`split_stream`](../trait.Senerator.html#method.split_stream).
+    /// Yields all the yielded items of the underlying Generator.
+    pub struct YieldStream<G: Senerator>(Rc<RefCell<Split<G>>>);
+
+    /// The `Future` half of [`split_stream`](../trait.Senerator.html#method.split_stream).
+    /// Resolves to the returned item of the underlying Generator, once the [`YieldStream`](struct.YieldStream.html) is drained.
+    pub struct ReturnFuture<G: Senerator>(Rc<RefCell<Split<G>>>);
+
+    pub(crate) fn split<G: Senerator>(gen: G) -> (YieldStream<G>, ReturnFuture<G>) {
+        let split = Rc::new(RefCell::new(Split {
+            gen,
+            ret: None,
+            drained: false,
+            waker: None,
+        }));
+
+        (YieldStream(split.clone()), ReturnFuture(split))
+    }
+
+    impl<G: Senerator> Stream for YieldStream<G> {
+        type Item = G::Yield;
+        type Error = ();
+
+        fn poll_next(&mut self, _cx: &mut Context) -> Poll<Option<Self::Item>, Self::Error> {
+            let mut split = self.0.borrow_mut();
+
+            if split.drained {
+                return Ok(Async::Ready(None));
+            }
+
+            match split.gen.resume_with_yield() {
+                Some(State::Yield(y)) => return Ok(Async::Ready(Some(y))),
+                Some(State::Return(r)) => split.ret = Some(r),
+                None => {}
+            }
+
+            split.drained = true;
+            if let Some(waker) = split.waker.take() {
+                waker.wake();
+            }
+            Ok(Async::Ready(None))
+        }
+    }
+
+    impl<G: Senerator> Future for ReturnFuture<G> {
+        type Item = G::Return;
+        type Error = ();
+
+        fn poll(&mut self, cx: &mut Context) -> Poll<Self::Item, Self::Error> {
+            let mut split = self.0.borrow_mut();
+
+            if !split.drained {
+                split.waker = Some(cx.waker().clone());
+                return Ok(Async::Pending);
+            }
+
+            match split.ret.take() {
+                Some(r) => Ok(Async::Ready(r)),
+                None => Err(()),
+            }
+        }
+    }
 }
@@ -1,4 +1,7 @@
-use gen::{Senerator, State};
+use adapters::Fuse;
+use gen::{Callable, ExactSizeSenerator, Senerator, State};
+use std::iter::FusedIterator;
+use std::ops::Generator;
 
 /// This trait converts any type implementing Gen to an Iterator.
 /// The Iterator should only return the Yield variants of [State](../gen/enum.State.html), and ignore the Return variant.
@@ -18,11 +21,25 @@ where
     type Iter = YieldIterator<Self>;
 
     fn iter_yielded(self) -> Self::Iter {
-        YieldIterator(self)
+        YieldIterator(self.fuse(), None)
     }
 }
 
-pub struct YieldIterator<G>(G);
+/// Iterates over the yielded items of a Senerator.
+/// The underlying Senerator is never resumed again after it returned.
+/// The size hint is only exact if it was created by [`iter_yielded_exact`](../gen/trait.ExactSizeSenerator.html#method.iter_yielded_exact).
+pub struct YieldIterator<G>(Fuse<G>, Option<usize>);
+
+impl<G> YieldIterator<G>
+where
+    G: ExactSizeSenerator,
+{
+    #[inline]
+    pub(crate) fn exact(gen: G) -> Self {
+        let remaining = gen.remaining();
+        YieldIterator(gen.fuse(), Some(remaining))
+    }
+}
 
 impl<G> Iterator for YieldIterator<G>
 where
@@ -32,12 +49,42 @@ where
 
     fn next(&mut self) -> Option<Self::Item> {
         match self.0.resume_with_yield() {
-            Some(State::Yield(y)) => Some(y),
-            _ => None,
+            Some(State::Yield(y)) => {
+                self.1 = self.1.map(|remaining| remaining.saturating_sub(1));
+                Some(y)
+            }
+            _ => {
+                self.1 = self.1.map(|_| 0);
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.1 {
+            Some(remaining) => (remaining, Some(remaining)),
+            None => (0, None),
         }
     }
 }
 
+impl<G> FusedIterator for YieldIterator<G> where G: Senerator {}
+
+/// Iterating over a Callable only gives the yielded items, just like [`iter_yielded`](trait.YieldIterExt.html#tymethod.iter_yielded).
+impl<G> IntoIterator for Callable<G>
+where
+    G: Generator,
+{
+    type Item = G::Yield;
+    type IntoIter = YieldIterator<Self>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_yielded()
+    }
+}
+
 /// This traits converts any type Implementing Gen<Yield = T, Return = R> into an Iterator, where R: Into<T>.
 /// This Iterator also returns the returned item from Gen.
 /// This is only possible if the Yield type and Return type are the same, or when the Return type can be transformed into the Yield type.
@@ -61,11 +108,68 @@ where
     type Iter = ReturnIterator<Self>;
 
     fn iter_all(self) -> Self::Iter {
-        ReturnIterator(self)
+        ReturnIterator(self.fuse(), (0, None))
     }
 }
 
-pub struct ReturnIterator<G>(G);
+/// Iterates over the yielded items, and the returned item of a Senerator.
+/// The underlying Senerator is never resumed again after it returned.
+/// The size hint is only exact if it was created by [`iter_all_exact`](../gen/trait.ExactSizeSenerator.html#method.iter_all_exact).
+pub struct ReturnIterator<G>(Fuse<G>, (usize, Option<usize>));
+
+impl<G> ReturnIterator<G>
+where
+    G: ExactSizeSenerator,
+{
+    #[inline]
+    pub(crate) fn exact(gen: G) -> Self {
+        // The returned item is an item as well, as long as it hasn't been returned yet.
+        // If that doesn't fit in a usize anymore, there is no upper bound to give.
+        let hint = if gen.is_done() {
+            (0, Some(0))
+        } else {
+            let remaining = gen.remaining();
+            (remaining.saturating_add(1), remaining.checked_add(1))
+        };
+        ReturnIterator(gen.fuse(), hint)
+    }
+}
+
+impl<G> ReturnIterator<G>
+where
+    G: Senerator,
+{
+    /// Resumes the underlying Senerator, keeping the size hint up to date.
+    #[inline]
+    fn next_state(&mut self) -> Option<State<G::Yield, G::Return>> {
+        match self.0.resume_with_yield() {
+            Some(state) => {
+                let (lower, upper) = self.1;
+                self.1 = (
+                    lower.saturating_sub(1),
+                    upper.map(|upper| upper.saturating_sub(1)),
+                );
+                Some(state)
+            }
+            None => {
+                self.1 = (0, Some(0));
+                None
+            }
+        }
+    }
+
+    /// Creates an Iterator that keeps a copy of the returned item around, see [`return_value`](struct.KeepReturn.html#method.return_value).
+    #[inline]
+    pub fn keep_return(self) -> KeepReturn<G>
+    where
+        G::Return: Clone,
+    {
+        KeepReturn {
+            iter: self,
+            ret: None,
+        }
+    }
+}
 
 impl<Y, R, G> Iterator for ReturnIterator<G>
 where
@@ -75,9 +179,67 @@ where
     type Item = Y;
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.0.resume_with_yield() {
-            Some(state) => state.into(),
-            None => None,
+        self.next_state().and_then(Into::into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.1
+    }
+}
+
+impl<Y, R, G> FusedIterator for ReturnIterator<G>
+where
+    G: Senerator<Yield = Y, Return = R>,
+    R: Into<Y>,
+{
+}
+
+/// Iterates over the yielded items, and the returned item of a Senerator, keeping a copy of the returned item around.
+/// Created by [`keep_return`](struct.ReturnIterator.html#method.keep_return).
+pub struct KeepReturn<G: Senerator> {
+    iter: ReturnIterator<G>,
+    ret: Option<G::Return>,
+}
+
+impl<G> KeepReturn<G>
+where
+    G: Senerator,
+{
+    /// Returns the returned item of the underlying Senerator.
+    /// Returns None if the Iterator hasn't reached the returned item yet.
+    #[inline]
+    pub fn return_value(&self) -> Option<&G::Return> {
+        self.ret.as_ref()
+    }
+}
+
+impl<Y, R, G> Iterator for KeepReturn<G>
+where
+    G: Senerator<Yield = Y, Return = R>,
+    R: Into<Y> + Clone,
+{
+    type Item = Y;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next_state()? {
+            State::Yield(y) => Some(y),
+            State::Return(r) => {
+                self.ret = Some(r.clone());
+                Some(r.into())
+            }
         }
     }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<Y, R, G> FusedIterator for KeepReturn<G>
+where
+    G: Senerator<Yield = Y, Return = R>,
+    R: Into<Y> + Clone,
+{
 }
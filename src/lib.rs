@@ -1,5 +1,5 @@
 //#![feature(extern_prelude)]
-#![feature(generator_trait, generators)]
+#![feature(generator_trait, generators, try_from, try_trait)]
 //! This crate is build for easy convertion from generators to iterators,
 //! and for `chaining` generators in different kinds of ways.
 
@@ -18,9 +18,32 @@ macro_rules! yield_from {
     };
 }
 
+/// A macro that sequences two or more Generators into a single [Callable](gen/struct.Callable.html).
+/// The new Callable yields all items of each Generator in turn, throwing away all returned values but the one of the last Generator,
+/// which becomes the returned value of the new Callable.
+/// Like [`yield_from`](macro.yield_from.html), this needs `Generator` and `GeneratorState` to be in scope.
+#[macro_export]
+macro_rules! chain {
+    (@rest $last:expr) => {{
+        let mut generator = $last;
+        yield_from!(generator)
+    }};
+    (@rest $g:expr, $($rest:expr),+) => {{
+        let mut generator = $g;
+        let _ = yield_from!(generator);
+        chain!(@rest $($rest),+)
+    }};
+    ($first:expr, $($rest:expr),+ $(,)*) => {
+        $crate::gen::Callable::new(move || {
+            return chain!(@rest $first, $($rest),+);
+        })
+    };
+}
+
 #[cfg(feature = "futuresext")]
 extern crate futures;
 
+pub mod adapters;
 pub mod gen;
 pub mod iter;
 
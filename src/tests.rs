@@ -1,8 +1,56 @@
 mod tests {
-    use gen::Callable;
-    use iter::ReturnIterExt;
+    use adapters::{interleave, merge_by, zip, zip_with};
+    use gen::{
+        empty, once, repeat, repeat_with, zip_returns, Callable, ExactSizeSenerator, Futerator,
+        Futor, IntoGenerator, Senerator, SeneratorExt, Senor, State, Stats,
+    };
+    use iter::{ReturnIterExt, YieldIterExt};
+    use std::collections::HashMap;
     use std::ops::{Generator, GeneratorState};
 
+    /// A hand-written Senerator that counts down, and panics when it is resumed after it returned.
+    #[derive(Clone)]
+    struct Countdown {
+        remaining: u32,
+        returned: bool,
+    }
+
+    impl Countdown {
+        fn new(remaining: u32) -> Self {
+            Countdown {
+                remaining,
+                returned: false,
+            }
+        }
+    }
+
+    impl Futerator for Countdown {
+        type Return = &'static str;
+
+        fn resume(&mut self) -> Futor<Self::Return> {
+            match self.resume_with_yield()? {
+                State::Yield(_) => Some(State::Yield(())),
+                State::Return(r) => Some(State::Return(r)),
+            }
+        }
+    }
+
+    impl Senerator for Countdown {
+        type Yield = u32;
+
+        fn resume_with_yield(&mut self) -> Senor<Self::Yield, Self::Return> {
+            assert!(!self.returned, "resumed a Countdown after it returned");
+
+            if self.remaining == 0 {
+                self.returned = true;
+                return Some(State::Return("liftoff"));
+            }
+
+            self.remaining -= 1;
+            Some(State::Yield(self.remaining))
+        }
+    }
+
     // #[test]
     // fn __test_generator_into_iterator() {
     //     let mut g = Callable::new(|| {
@@ -130,4 +178,2025 @@ mod tests {
         assert_eq!(iter.next(), Some(9));
         assert_eq!(iter.next(), Some(2));
     }
+
+    #[test]
+    fn test_enumerate_yields_from() {
+        let mut g = Callable::new(|| {
+            yield 'a';
+            yield 'b';
+            yield 'c';
+            return 'd';
+        })
+        .enumerate_yields_from(100);
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec![(100, 'a'), (101, 'b'), (102, 'c')]
+        );
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, 'd'),
+            _ => panic!("expected the return"),
+        }
+        assert!(g.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_fold_state() {
+        let mut g = Callable::new(|| {
+            for i in &[1, 2, 1, 3, 1, 2] {
+                yield *i;
+            }
+            return "done";
+        });
+
+        let (histogram, ret) = g.fold_state(HashMap::new(), |histogram, y| {
+            *histogram.entry(y).or_insert(0) += 1;
+        });
+
+        assert_eq!(histogram[&1], 3);
+        assert_eq!(histogram[&2], 2);
+        assert_eq!(histogram[&3], 1);
+        assert_eq!(ret, Some("done"));
+
+        let (_, ret) = g.fold_state((), |_, _| {});
+        assert_eq!(ret, None);
+    }
+
+    #[test]
+    fn test_peekable_with_return() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        })
+        .peekable();
+
+        assert_eq!(g.peek(), Some(&1));
+        assert_eq!(g.peek(), Some(&1));
+        assert!(!g.peek_is_return());
+        assert_eq!(
+            (&mut g).iter_yielded().take(2).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+
+        assert!(g.peek_is_return());
+        assert_eq!(g.peek(), None);
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, 3),
+            _ => panic!("expected the return"),
+        }
+        assert!(!g.peek_is_return());
+        assert!(g.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_fuse_after_return() {
+        let mut iter = Countdown::new(3).iter_yielded();
+        assert_eq!((&mut iter).collect::<Vec<_>>(), vec![2, 1, 0]);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        let mut g = Countdown::new(1).fuse();
+        assert!(g.resume_with_yield().is_some());
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, "liftoff"),
+            _ => panic!("expected the return"),
+        }
+        assert!(g.resume_with_yield().is_none());
+        assert!(g.resume().is_none());
+    }
+
+    #[test]
+    fn test_indexed_collect() {
+        let (collected, ret) = Callable::new(|| {
+            yield 'a';
+            yield 'b';
+            yield 'c';
+            return 3;
+        })
+        .indexed_collect();
+
+        assert_eq!(collected, vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+        assert_eq!(ret, 3);
+    }
+
+    #[test]
+    fn test_next_yield_or() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            return 2;
+        });
+
+        assert_eq!(g.next_yield_or(0), 1);
+        assert_eq!(g.next_yield_or(0), 0);
+        assert_eq!(g.next_yield_or(0), 0);
+    }
+
+    #[test]
+    fn test_next_yield_or_else() {
+        let mut called = 0;
+        let mut g = Callable::new(|| {
+            yield 1;
+            return 2;
+        });
+
+        assert_eq!(
+            g.next_yield_or_else(|| {
+                called += 1;
+                0
+            }),
+            1
+        );
+        assert_eq!(called, 0);
+        assert_eq!(
+            g.next_yield_or_else(|| {
+                called += 1;
+                10
+            }),
+            10
+        );
+        assert_eq!(called, 1);
+    }
+
+    #[test]
+    fn test_tuple_windows_2() {
+        let mut g = Callable::new(|| {
+            for i in 1..5 {
+                yield i;
+            }
+            return 5;
+        })
+        .tuple_windows_2();
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec![(1, 2), (2, 3), (3, 4)]
+        );
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, 5),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_collect_exact() {
+        let mut g = Callable::new(|| {
+            for i in 0..4 {
+                yield i;
+            }
+            return 4;
+        });
+
+        assert_eq!(g.collect_exact(3), Some(vec![0, 1, 2]));
+        assert_eq!(g.next_yield_or(-1), 3);
+    }
+
+    #[test]
+    fn test_collect_exact_ends_early() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        });
+
+        assert_eq!(g.collect_exact(3), None);
+        assert!(g.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_chain_return_iter() {
+        let generator = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        });
+
+        let (collected, ret) = generator
+            .chain_return_iter(|ret| vec![ret * 10, ret * 20, ret * 30])
+            .unwrap()
+            .fold_state(Vec::new(), |collected, y| collected.push(y));
+
+        assert_eq!(collected, vec![1, 2, 30, 60, 90]);
+        assert_eq!(ret, Some(()));
+    }
+
+    #[test]
+    fn test_drain_count() {
+        let g = Callable::new(|| {
+            for i in 0..100_000u32 {
+                yield i;
+            }
+            return "done";
+        });
+
+        assert_eq!(g.drain_count(), 100_000);
+    }
+
+    #[test]
+    fn test_split_first_yield() {
+        let (first, rest) = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return 4;
+        })
+        .split_first_yield();
+
+        assert_eq!(first, Some(1));
+        assert_eq!(rest.iter_all().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        let (first, mut rest) = Callable::new(|| {
+            if false {
+                yield 1;
+            }
+            return 2;
+        })
+        .split_first_yield();
+
+        assert_eq!(first, None);
+        match rest.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, 2),
+            _ => panic!("expected the return"),
+        }
+        assert!(rest.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_zip_returns() {
+        let a = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return "three";
+        });
+
+        let b = Callable::new(|| {
+            yield 1.5;
+            return 'b';
+        });
+
+        assert_eq!(zip_returns(a, b), ("three", 'b'));
+    }
+
+    #[test]
+    fn test_chain_if_proceeds() {
+        let chained = Callable::new(|| {
+            yield 1;
+            return 2;
+        })
+        .chain_if(
+            |ret| *ret > 0,
+            |ret| {
+                move || {
+                    yield ret * 10;
+                    return ret * 100;
+                }
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chained.iter_all().collect::<Vec<_>>(), vec![1, 20, 200]);
+    }
+
+    #[test]
+    fn test_chain_if_aborts() {
+        let chained = Callable::new(|| {
+            yield 1;
+            return -2;
+        })
+        .chain_if(
+            |ret| *ret > 0,
+            |ret| {
+                move || {
+                    yield ret * 10;
+                    return ret * 100;
+                }
+            },
+        )
+        .unwrap();
+
+        assert_eq!(chained.iter_all().collect::<Vec<_>>(), vec![1, -2]);
+    }
+
+    #[test]
+    fn test_accumulate_yields() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return 0;
+        })
+        .accumulate_yields(|acc, y| acc + y);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 3, 6, 0]);
+    }
+
+    #[test]
+    fn test_try_into_yields() {
+        let mut g = Callable::new(|| {
+            for i in &[0i64, 255, 256, -1] {
+                yield *i;
+            }
+        })
+        .try_into_yields::<u8>();
+
+        assert_eq!(g.next_yield_or(Ok(1)), Ok(0));
+        assert_eq!(g.next_yield_or(Ok(1)), Ok(255));
+        assert!(g.next_yield_or(Ok(1)).is_err());
+        assert!(g.next_yield_or(Ok(1)).is_err());
+        assert!(g.resume_with_yield().is_some());
+        assert!(g.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_sink_into_return() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return "discarded";
+        })
+        .sink_into_return(0, |acc, y| acc + y);
+
+        let mut steps = 0;
+        let sum = loop {
+            match g.resume() {
+                Some(State::Yield(())) => steps += 1,
+                Some(State::Return(sum)) => break sum,
+                None => panic!("exhausted before returning"),
+            }
+        };
+
+        assert_eq!(steps, 3);
+        assert_eq!(sum, 6);
+        assert!(g.resume().is_none());
+    }
+
+    #[test]
+    fn test_flatten_iter() {
+        let g = Callable::new(|| {
+            yield vec![1, 2];
+            yield vec![];
+            yield vec![3];
+            return 4;
+        })
+        .flatten_iter();
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_drive_limited_completes() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        });
+
+        assert_eq!(g.drive_limited(3), Ok(3));
+    }
+
+    #[test]
+    fn test_drive_limited_budget_exhausted() {
+        let mut g = Callable::new(|| {
+            for i in 0..5 {
+                yield i;
+            }
+            return 5;
+        });
+
+        assert_eq!(g.drive_limited(2), Err(vec![0, 1]));
+        assert_eq!(g.drive_limited(2), Err(vec![2, 3]));
+        assert_eq!(g.drive_limited(2), Ok(5));
+        assert_eq!(g.drive_limited(2), Err(vec![]));
+    }
+
+    #[test]
+    fn test_map_state_indexed() {
+        let g = Callable::new(|| {
+            for i in 10..14 {
+                yield i;
+            }
+            return 14;
+        })
+        .map_state_indexed(|index, state| match state {
+            State::Yield(y) if index % 2 == 0 => State::Yield(format!("even {}", y)),
+            State::Yield(y) => State::Yield(format!("odd {}", y)),
+            State::Return(r) => State::Return(format!("return {} at {}", r, index)),
+        });
+
+        assert_eq!(
+            g.iter_all().collect::<Vec<_>>(),
+            vec!["even 10", "odd 11", "even 12", "odd 13", "return 14 at 4"]
+        );
+    }
+
+    #[test]
+    fn test_collect_pairs() {
+        let (pairs, leftover, ret) = Callable::new(|| {
+            for i in 1..6 {
+                yield i;
+            }
+            return "done";
+        })
+        .collect_pairs();
+
+        assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+        assert_eq!(leftover, Some(5));
+        assert_eq!(ret, "done");
+    }
+
+    #[test]
+    fn test_watch() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let steps = Arc::new(AtomicUsize::new(0));
+        let counter = steps.clone();
+
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        })
+        .watch(move |_| {
+            counter.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(steps.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_split_at() {
+        let (head, rest) = Callable::new(|| {
+            for i in 0..5 {
+                yield i;
+            }
+            return 5;
+        })
+        .split_at(2);
+
+        assert_eq!(head, vec![0, 1]);
+        assert_eq!(rest.iter_all().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+
+        let (head, rest) = Callable::new(|| {
+            yield 0;
+            return 1;
+        })
+        .split_at(3);
+
+        assert_eq!(head, vec![0]);
+        assert_eq!(rest.iter_all().collect::<Vec<_>>(), vec![1]);
+    }
+
+    #[test]
+    fn test_map_return_with_yields() {
+        let mut g = Callable::new(|| {
+            yield "a";
+            yield "b";
+            yield "c";
+            return "done";
+        })
+        .map_return_with_yields(
+            0,
+            |count, _| *count += 1,
+            |count, ret| format!("{} after {} yields", ret, count),
+        );
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, "done after 3 yields"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_send_all_sync() {
+        use std::sync::mpsc::sync_channel;
+        use std::thread;
+        use std::time::Duration;
+
+        let (tx, rx) = sync_channel(1);
+
+        let consumer = thread::spawn(move || {
+            let mut received = Vec::new();
+            for item in rx {
+                thread::sleep(Duration::from_millis(1));
+                received.push(item);
+            }
+            received
+        });
+
+        let ret = Callable::new(|| {
+            for i in 0..10 {
+                yield i;
+            }
+            return "sent";
+        })
+        .send_all_sync(tx);
+
+        assert_eq!(ret, "sent");
+        assert_eq!(consumer.join().unwrap(), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_collect_on_ok() {
+        let collected: Result<(Vec<_>, _), &str> = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return Ok(3);
+        })
+        .collect_on_ok();
+
+        assert_eq!(collected, Ok((vec![1, 2], 3)));
+    }
+
+    #[test]
+    fn test_collect_on_ok_err() {
+        let collected: Result<(Vec<_>, u8), _> = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return Err("failed");
+        })
+        .collect_on_ok();
+
+        assert_eq!(collected, Err("failed"));
+    }
+
+    #[test]
+    fn test_segments() {
+        let chain = Callable::new(|| {
+            yield State::Yield(1);
+            yield State::Yield(2);
+            return 'a';
+        })
+        .chain(|ret| {
+            move || {
+                yield State::Return(ret);
+                yield State::Yield(3);
+                return 'b';
+            }
+        })
+        .unwrap()
+        .chain(|ret| {
+            move || {
+                yield State::Return(ret);
+                return 'c';
+            }
+        })
+        .unwrap();
+
+        assert_eq!(
+            chain.segments(),
+            vec![(vec![1, 2], 'a'), (vec![3], 'b'), (vec![], 'c')]
+        );
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 10;
+        })
+        .map_in_place(|y| *y += 1);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![2, 3, 10]);
+    }
+
+    #[test]
+    fn test_join_to_string() {
+        let (joined, ret) = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return 'r';
+        })
+        .join_to_string(", ");
+
+        assert_eq!(joined, "1, 2, 3");
+        assert_eq!(ret, 'r');
+    }
+
+    #[test]
+    fn test_guarded_poison() {
+        let mut g = Countdown::new(5).guarded();
+
+        assert_eq!(g.next_yield_or(99), 4);
+        assert_eq!(g.next_yield_or(99), 3);
+        assert!(!g.is_poisoned());
+
+        g.poison();
+        assert!(g.is_poisoned());
+        assert!(g.resume_with_yield().is_none());
+        assert!(g.resume().is_none());
+        assert_eq!(g.iter_yielded().count(), 0);
+    }
+
+    #[test]
+    fn test_collect_unique() {
+        let (unique, ret): (Vec<_>, _) = Callable::new(|| {
+            for i in &[1, 2, 1, 3, 2] {
+                yield *i;
+            }
+            return 5;
+        })
+        .collect_unique();
+
+        assert_eq!(unique, vec![1, 2, 3]);
+        assert_eq!(ret, 5);
+    }
+
+    #[test]
+    fn test_interruptible() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::mpsc::channel;
+        use std::sync::Arc;
+        use std::thread;
+
+        let flag = Arc::new(AtomicBool::new(false));
+        let (tx, rx) = channel();
+
+        let interrupter = {
+            let flag = flag.clone();
+            thread::spawn(move || {
+                rx.recv().unwrap();
+                flag.store(true, Ordering::SeqCst);
+            })
+        };
+
+        let mut g = Callable::new(|| {
+            let mut i = 0;
+            loop {
+                yield i;
+                i += 1;
+            }
+        })
+        .interruptible(flag);
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+
+        tx.send(()).unwrap();
+        interrupter.join().unwrap();
+
+        assert!(g.resume_with_yield().is_none());
+        assert_eq!(g.iter_yielded().count(), 0);
+    }
+
+    #[test]
+    fn test_try_flatten_yields() {
+        let g = Callable::new(|| {
+            yield Ok(vec![1, 2]);
+            yield Err("e");
+            yield Ok(vec![3]);
+            return Ok(4);
+        })
+        .try_flatten_yields();
+
+        assert_eq!(
+            g.iter_all().collect::<Vec<_>>(),
+            vec![Ok(1), Ok(2), Err("e"), Ok(3), Ok(4)]
+        );
+    }
+
+    #[test]
+    fn test_minmax_yields() {
+        let (minmax, ret) = Callable::new(|| {
+            for i in &[3, 1, 4, 1, 5, 9, 2, 6] {
+                yield *i;
+            }
+            return "pi";
+        })
+        .minmax_yields();
+
+        assert_eq!(minmax, Some((1, 9)));
+        assert_eq!(ret, "pi");
+
+        let (minmax, ret) = Callable::new(|| {
+            if false {
+                yield 0;
+            }
+            return "empty";
+        })
+        .minmax_yields();
+
+        assert_eq!(minmax, None);
+        assert_eq!(ret, "empty");
+    }
+
+    #[test]
+    fn test_first_last_zero() {
+        let (first, last, ret) = Callable::new(|| {
+            if false {
+                yield 0;
+            }
+            return 'r';
+        })
+        .first_last();
+
+        assert_eq!((first, last, ret), (None, None, 'r'));
+    }
+
+    #[test]
+    fn test_first_last_one() {
+        let (first, last, ret) = Callable::new(|| {
+            yield 1;
+            return 'r';
+        })
+        .first_last();
+
+        assert_eq!((first, last, ret), (Some(1), Some(1), 'r'));
+    }
+
+    #[test]
+    fn test_first_last_many() {
+        let (first, last, ret) = Callable::new(|| {
+            for i in 1..5 {
+                yield i;
+            }
+            return 'r';
+        })
+        .first_last();
+
+        assert_eq!((first, last, ret), (Some(1), Some(4), 'r'));
+    }
+
+    #[test]
+    fn test_group_into_map() {
+        let (groups, ret) = Callable::new(|| {
+            yield ("a", 1);
+            yield ("b", 2);
+            yield ("a", 3);
+            return 3;
+        })
+        .group_into_map();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups["a"], vec![1, 3]);
+        assert_eq!(groups["b"], vec![2]);
+        assert_eq!(ret, 3);
+    }
+
+    #[test]
+    fn test_alternate() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return 3;
+        })
+        .alternate(0);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 1, 0, 2, 0, 3]);
+
+        let g = Callable::new(|| {
+            if false {
+                yield 1;
+            }
+            return 3;
+        })
+        .alternate(0);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 3]);
+    }
+
+    #[test]
+    fn test_collect_boxed() {
+        let (boxed, ret) = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return "boxed";
+        })
+        .collect_boxed();
+
+        assert_eq!(&*boxed, &[1, 2, 3]);
+        assert_eq!(ret, "boxed");
+    }
+
+    #[test]
+    fn test_partition_results() {
+        let (oks, errs, ret): (Vec<_>, Vec<_>, _) = Callable::new(|| {
+            yield Ok(1);
+            yield Err("two");
+            yield Ok(3);
+            yield Err("four");
+            return 4;
+        })
+        .partition_results();
+
+        assert_eq!(oks, vec![1, 3]);
+        assert_eq!(errs, vec!["two", "four"]);
+        assert_eq!(ret, 4);
+    }
+
+    #[test]
+    fn test_with_progress() {
+        let mut progress = Vec::new();
+
+        {
+            let g = Callable::new(|| {
+                yield 'a';
+                yield 'b';
+                yield 'c';
+                return 'd';
+            })
+            .with_progress(3, |completed, total| progress.push((completed, total)));
+
+            assert_eq!(g.iter_all().collect::<String>(), "abcd");
+        }
+
+        assert_eq!(progress, vec![(1, 3), (2, 3), (3, 3)]);
+    }
+
+    #[test]
+    fn test_histogram() {
+        let (histogram, ret) = Callable::new(|| {
+            for c in "abaab".chars() {
+                yield c;
+            }
+            return 5;
+        })
+        .histogram();
+
+        assert_eq!(histogram.len(), 2);
+        assert_eq!(histogram[&'a'], 3);
+        assert_eq!(histogram[&'b'], 2);
+        assert_eq!(ret, 5);
+    }
+
+    #[test]
+    fn test_positions() {
+        let (positions, ret) = Callable::new(|| {
+            for i in 1..7 {
+                yield i;
+            }
+            return "done";
+        })
+        .positions(|y| y % 2 == 0);
+
+        assert_eq!(positions, vec![1, 3, 5]);
+        assert_eq!(ret, "done");
+    }
+
+    #[test]
+    fn test_every_other_keep_first() {
+        let g = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 6;
+        })
+        .every_other(true);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_every_other_skip_first() {
+        let g = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 6;
+        })
+        .every_other(false);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 3, 5, 6]);
+    }
+
+    #[test]
+    fn test_running_stats() {
+        let (stats, ret) = Callable::new(|| {
+            yield 1.0;
+            yield 2.0;
+            yield 3.0;
+            return "stats";
+        })
+        .running_stats();
+
+        assert_eq!(
+            stats,
+            Stats {
+                count: 3,
+                sum: 6.0,
+                mean: Some(2.0),
+                min: Some(1.0),
+                max: Some(3.0),
+            }
+        );
+        assert_eq!(ret, "stats");
+    }
+
+    #[test]
+    fn test_map_yield() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return "done";
+        })
+        .map_yield(|y| y * 10);
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(2).collect::<Vec<_>>(),
+            vec![10, 20]
+        );
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, "done"),
+            _ => panic!("expected the return"),
+        }
+        assert!(g.resume_with_yield().is_none());
+
+        let identity = Callable::new(|| {
+            yield 1;
+            return 2;
+        })
+        .map_yield(|y| y);
+
+        assert_eq!(identity.iter_all().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_filter_yield() {
+        let g = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 99;
+        })
+        .filter_yield(|y| y % 2 == 0);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 2, 4, 99]);
+    }
+
+    #[test]
+    fn test_filter_yield_all() {
+        let g = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 99;
+        })
+        .filter_yield(|_| false);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![99]);
+    }
+
+    #[test]
+    fn test_take_while() {
+        let mut calls = 0;
+
+        {
+            let g = Callable::new(|| {
+                for i in 0..10 {
+                    yield i;
+                }
+                return 99;
+            })
+            .take_while(|y| {
+                calls += 1;
+                *y < 3
+            });
+
+            assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 1, 2, 99]);
+        }
+
+        assert_eq!(calls, 4);
+
+        let mut g = Callable::new(|| {
+            yield 1;
+            yield 5;
+            yield 2;
+            return 99;
+        })
+        .take_while(|y| *y < 3);
+
+        assert_eq!(g.next_yield_or(0), 1);
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, 99),
+            _ => panic!("expected the return"),
+        }
+        assert!(g.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_skip_while() {
+        let mut calls = 0;
+
+        {
+            let g = Callable::new(|| {
+                for i in &[1, 2, 3, 10, 1, 2] {
+                    yield *i;
+                }
+                return 99;
+            })
+            .skip_while(|y| {
+                calls += 1;
+                *y < 4
+            });
+
+            assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![10, 1, 2, 99]);
+        }
+
+        assert_eq!(calls, 4);
+    }
+
+    #[test]
+    fn test_enumerate() {
+        let mut g = Callable::new(|| {
+            yield 'a';
+            yield 'b';
+            yield 'c';
+            return "done";
+        })
+        .enumerate();
+
+        assert_eq!(
+            (&mut g).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec![(0, 'a'), (1, 'b'), (2, 'c')]
+        );
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, "done"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut seen = Vec::new();
+
+        {
+            let g = Callable::new(|| {
+                yield 1;
+                yield 2;
+                yield 3;
+                return 99;
+            })
+            .inspect(|y| seen.push(*y))
+            .map_yield(|y| y * 2);
+
+            assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![2, 4, 6, 99]);
+        }
+
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_scan() {
+        let g = Callable::new(|| {
+            for i in 1..5 {
+                yield i;
+            }
+            return 99;
+        })
+        .scan(0, |sum, y| {
+            *sum += y;
+            Some(*sum)
+        });
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 3, 6, 10, 99]);
+    }
+
+    #[test]
+    fn test_scan_stops_early() {
+        let g = Callable::new(|| {
+            for i in 1..5 {
+                yield i;
+            }
+            return 99;
+        })
+        .scan(0, |sum, y| {
+            *sum += y;
+            if *sum > 3 {
+                None
+            } else {
+                Some(*sum)
+            }
+        });
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 3, 99]);
+    }
+
+    #[test]
+    fn test_step_by() {
+        let g = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 99;
+        })
+        .step_by(2);
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![0, 2, 4, 99]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_step_by_zero() {
+        Countdown::new(1).step_by(0);
+    }
+
+    #[test]
+    fn test_chunks_exact() {
+        let (chunks, ret) = Callable::new(|| {
+            for i in 0..6 {
+                yield i;
+            }
+            return 99;
+        })
+        .chunks(3)
+        .fold_state(Vec::new(), |chunks, chunk| chunks.push(chunk));
+
+        assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+        assert_eq!(ret, Some(99));
+    }
+
+    #[test]
+    fn test_chunks_partial() {
+        let (chunks, ret) = Callable::new(|| {
+            for i in 0..5 {
+                yield i;
+            }
+            return 99;
+        })
+        .chunks(2)
+        .fold_state(Vec::new(), |chunks, chunk| chunks.push(chunk));
+
+        assert_eq!(chunks, vec![vec![0, 1], vec![2, 3], vec![4]]);
+        assert_eq!(ret, Some(99));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chunks_zero() {
+        Countdown::new(1).chunks(0);
+    }
+
+    #[test]
+    fn test_windows() {
+        let (windows, ret) = Callable::new(|| {
+            for i in 1..5 {
+                yield i;
+            }
+            return 99;
+        })
+        .windows(2)
+        .fold_state(Vec::new(), |windows, window| windows.push(window));
+
+        assert_eq!(windows, vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+        assert_eq!(ret, Some(99));
+    }
+
+    #[test]
+    fn test_windows_too_short() {
+        let (windows, ret) = Callable::new(|| {
+            yield 1;
+            return 99;
+        })
+        .windows(2)
+        .fold_state(Vec::new(), |windows, window| windows.push(window));
+
+        assert!(windows.is_empty());
+        assert_eq!(ret, Some(99));
+    }
+
+    #[test]
+    fn test_intersperse() {
+        let g = Callable::new(|| {
+            yield "a";
+            yield "b";
+            yield "c";
+            return "!";
+        })
+        .intersperse(", ");
+
+        assert_eq!(g.iter_all().collect::<String>(), "a, b, c!");
+    }
+
+    #[test]
+    fn test_intersperse_zero_and_one() {
+        let zero = Callable::new(|| {
+            if false {
+                yield "a";
+            }
+            return "!";
+        })
+        .intersperse(", ");
+
+        assert_eq!(zero.iter_all().collect::<String>(), "!");
+
+        let one = Callable::new(|| {
+            yield "a";
+            return "!";
+        })
+        .intersperse(", ");
+
+        assert_eq!(one.iter_all().collect::<String>(), "a!");
+    }
+
+    #[test]
+    fn test_dedup() {
+        let g = Callable::new(|| {
+            for i in &[1, 1, 2, 2, 1] {
+                yield *i;
+            }
+            return 1;
+        })
+        .dedup();
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 2, 1, 1]);
+    }
+
+    #[test]
+    fn test_zip() {
+        let a = Callable::new(|| {
+            for i in 0..5 {
+                yield i;
+            }
+            return "a";
+        });
+
+        let b = Callable::new(|| {
+            yield 'x';
+            yield 'y';
+            return 'b';
+        });
+
+        let (pairs, ret) = zip(a, b).fold_state(Vec::new(), |pairs, pair| pairs.push(pair));
+
+        assert_eq!(pairs, vec![(0, 'x'), (1, 'y')]);
+        assert_eq!(ret, Some(("a", 'b')));
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a = Callable::new(|| {
+            for i in 1..4 {
+                yield i;
+            }
+            return 'a';
+        });
+
+        let b = Callable::new(|| {
+            for i in 10..20 {
+                yield i;
+            }
+            return 'b';
+        });
+
+        let (products, ret) =
+            zip_with(a, b, |a, b| a * b).fold_state(Vec::new(), |products, p| products.push(p));
+
+        assert_eq!(products, vec![10, 22, 36]);
+        assert_eq!(ret, Some(('a', 'b')));
+    }
+
+    #[test]
+    fn test_interleave_equal_length() {
+        let a = Callable::new(|| {
+            yield 1;
+            yield 3;
+            return 'a';
+        });
+
+        let b = Callable::new(|| {
+            yield 2;
+            yield 4;
+            return "b";
+        });
+
+        let (yielded, ret) = interleave(a, b).fold_state(Vec::new(), |yielded, y| yielded.push(y));
+
+        assert_eq!(yielded, vec![1, 2, 3, 4]);
+        assert_eq!(ret, Some(('a', "b")));
+    }
+
+    #[test]
+    fn test_interleave_unequal_length() {
+        let a = Callable::new(|| {
+            yield 1;
+            return 'a';
+        });
+
+        let b = Callable::new(|| {
+            for i in &[2, 3, 4] {
+                yield *i;
+            }
+            return "b";
+        });
+
+        let (yielded, ret) = interleave(a, b).fold_state(Vec::new(), |yielded, y| yielded.push(y));
+
+        assert_eq!(yielded, vec![1, 2, 3, 4]);
+        assert_eq!(ret, Some(('a', "b")));
+    }
+
+    #[test]
+    fn test_merge_by() {
+        let a = Callable::new(|| {
+            for i in &[1, 3, 5] {
+                yield *i;
+            }
+            return 'a';
+        });
+
+        let b = Callable::new(|| {
+            for i in &[2, 4, 6] {
+                yield *i;
+            }
+            return 'b';
+        });
+
+        let mut merged = merge_by(a, b, |a, b| a <= b);
+        let (yielded, ret) = merged.fold_state(Vec::new(), |yielded, y| yielded.push(y));
+
+        assert_eq!(yielded, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(ret, Some(('a', 'b')));
+        assert!(merged.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_flatten() {
+        fn count_to(n: i32) -> impl Generator<Yield = i32, Return = i32> {
+            move || {
+                for i in 1..n + 1 {
+                    yield i;
+                }
+                return -n;
+            }
+        }
+
+        let g = Callable::new(|| {
+            yield count_to(1);
+            yield count_to(2);
+            yield count_to(3);
+            return 0;
+        })
+        .flatten();
+
+        assert_eq!(g.iter_all().collect::<Vec<_>>(), vec![1, 1, 2, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn test_chain_callable() {
+        let first = Callable::new(|| {
+            yield 1;
+            yield 2;
+            return "ignored";
+        });
+
+        let second = Callable::new(|| {
+            yield 3;
+            return 4;
+        });
+
+        let chained = first.chain_callable(second).unwrap();
+
+        assert_eq!(chained.iter_all().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_chain_macro() {
+        let g1 = || {
+            yield 1;
+            return 10;
+        };
+        let g2 = || {
+            yield 2;
+            yield 3;
+            return 20;
+        };
+        let g3 = || {
+            yield 4;
+            return 30;
+        };
+
+        let chained = chain!(g1, g2, g3);
+
+        assert_eq!(chained.iter_all().collect::<Vec<_>>(), vec![1, 2, 3, 4, 30]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let chained = Callable::from_iter(0..3)
+            .chain(|()| {
+                || {
+                    yield 3;
+                    yield 4;
+                }
+            })
+            .unwrap();
+
+        assert_eq!(
+            chained.iter_yielded().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn test_into_generator() {
+        let mut generator = Callable::new(vec![0, 1, 2].into_generator());
+
+        assert_eq!(
+            (&mut generator).iter_yielded().collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+        assert!(generator.resume_with_yield().is_none());
+    }
+
+    #[test]
+    fn test_once() {
+        assert_eq!(
+            once("hello").iter_yielded().collect::<Vec<_>>(),
+            vec!["hello"]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut g = empty::<u32, Vec<u32>>();
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert!(r.is_empty()),
+            _ => panic!("expected the return"),
+        }
+
+        assert_eq!(empty::<u32, ()>().iter_yielded().count(), 0);
+    }
+
+    #[test]
+    fn test_repeat() {
+        assert_eq!(
+            repeat(7).iter_yielded().take(3).collect::<Vec<_>>(),
+            vec![7, 7, 7]
+        );
+    }
+
+    #[test]
+    fn test_repeat_with() {
+        let mut calls = 0;
+
+        {
+            let mut iter = repeat_with(|| {
+                calls += 1;
+                calls * 10
+            })
+            .iter_yielded();
+
+            assert_eq!(iter.next(), Some(10));
+            assert_eq!(iter.next(), Some(20));
+            assert_eq!(iter.next(), Some(30));
+        }
+
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let cycled = Countdown::new(2)
+            .cycle()
+            .iter_yielded()
+            .map(|y| 2 - y)
+            .take(5)
+            .collect::<Vec<_>>();
+
+        assert_eq!(cycled, vec![1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn test_cycle_without_yields() {
+        let mut g = Countdown::new(0).cycle();
+
+        match g.resume_with_yield() {
+            Some(State::Return(r)) => assert_eq!(r, "liftoff"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_callable_into_iterator() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return "not iterated";
+        });
+
+        let mut seen = Vec::new();
+        for y in g {
+            seen.push(y);
+        }
+
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_fused_iterators() {
+        fn assert_fused<I: ::std::iter::FusedIterator>(_: &I) {}
+
+        let yields = Countdown::new(2).iter_yielded();
+        let all = Callable::new(|| {
+            yield 1;
+            return 2;
+        })
+        .iter_all();
+
+        assert_fused(&yields);
+        assert_fused(&all);
+    }
+
+    #[test]
+    fn test_size_hint() {
+        let mut yields = Callable::from_iter(0..5).iter_yielded_exact();
+        assert_eq!(yields.size_hint(), (5, Some(5)));
+
+        yields.next();
+        assert_eq!(yields.size_hint(), (4, Some(4)));
+        assert_eq!(yields.collect::<Vec<_>>().len(), 4);
+
+        let mut all = Callable::from_iter(vec![(); 2]).iter_all_exact();
+        assert_eq!(all.size_hint(), (3, Some(3)));
+        assert_eq!(all.by_ref().count(), 3);
+        assert_eq!(all.size_hint(), (0, Some(0)));
+
+        assert_eq!(Countdown::new(3).iter_yielded().size_hint(), (0, None));
+    }
+
+    #[test]
+    fn test_size_hint_exhausted() {
+        let mut g = Callable::from_iter(vec![(); 2]);
+        assert_eq!(g.by_ref().count_yields(), (2, ()));
+
+        let mut all = g.iter_all_exact();
+        assert_eq!(all.size_hint(), (0, Some(0)));
+        assert_eq!(all.next(), None);
+    }
+
+    #[test]
+    fn test_size_hint_overflow() {
+        let mut all = Callable::from_iter((0..usize::max_value()).map(|_| ())).iter_all_exact();
+        assert_eq!(all.size_hint(), (usize::max_value(), None));
+
+        all.next();
+        assert_eq!(all.size_hint(), (usize::max_value() - 1, None));
+    }
+
+    #[test]
+    fn test_state_map_yield() {
+        match State::Yield::<u32, &str>(2).map_yield(|y| y * 10) {
+            State::Yield(y) => assert_eq!(y, 20),
+            _ => panic!("expected the yield"),
+        }
+
+        match State::Return::<u32, &str>("done").map_yield(|y| y * 10) {
+            State::Return(r) => assert_eq!(r, "done"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_state_map_return() {
+        match State::Yield::<u32, &str>(2).map_return(|r| r.len()) {
+            State::Yield(y) => assert_eq!(y, 2),
+            _ => panic!("expected the yield"),
+        }
+
+        match State::Return::<u32, &str>("done").map_return(|r| r.len()) {
+            State::Return(r) => assert_eq!(r, 4),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_state_as_ref() {
+        let state = State::Yield::<Vec<u32>, String>(vec![1, 2]);
+
+        match state.as_ref() {
+            State::Yield(y) => assert_eq!(y, &vec![1, 2]),
+            _ => panic!("expected the yield"),
+        }
+
+        let state = State::Return::<Vec<u32>, String>(String::from("done"));
+
+        match state.as_ref() {
+            State::Return(r) => assert_eq!(r, "done"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_state_as_mut() {
+        let mut state = State::Yield::<Vec<u32>, String>(vec![1, 2]);
+
+        if let State::Yield(y) = state.as_mut() {
+            y.push(3);
+        }
+
+        match state {
+            State::Yield(y) => assert_eq!(y, vec![1, 2, 3]),
+            _ => panic!("expected the yield"),
+        }
+
+        let mut state = State::Return::<Vec<u32>, String>(String::from("done"));
+
+        if let State::Return(r) = state.as_mut() {
+            r.push('!');
+        }
+
+        match state {
+            State::Return(r) => assert_eq!(r, "done!"),
+            _ => panic!("expected the return"),
+        }
+    }
+
+    #[test]
+    fn test_state_is_yield_is_return() {
+        let yielded = State::Yield::<u32, u32>(1);
+        let returned = State::Return::<u32, u32>(2);
+
+        assert!(yielded.is_yield());
+        assert!(!yielded.is_return());
+        assert!(returned.is_return());
+        assert!(!returned.is_yield());
+    }
+
+    #[test]
+    fn test_state_yielded_returned() {
+        assert_eq!(State::Yield::<u32, u32>(1).yielded(), Some(1));
+        assert_eq!(State::Yield::<u32, u32>(1).returned(), None);
+        assert_eq!(State::Return::<u32, u32>(2).yielded(), None);
+        assert_eq!(State::Return::<u32, u32>(2).returned(), Some(2));
+    }
+
+    #[test]
+    fn test_state_unwrap() {
+        assert_eq!(State::Yield::<u32, &str>(1).unwrap_yield(), 1);
+        assert_eq!(State::Return::<u32, &str>("done").unwrap_return(), "done");
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_yield on a Return")]
+    fn test_state_unwrap_yield_on_return() {
+        State::Return::<u32, &str>("done").unwrap_yield();
+    }
+
+    #[test]
+    #[should_panic(expected = "called unwrap_return on a Yield")]
+    fn test_state_unwrap_return_on_yield() {
+        State::Yield::<u32, &str>(1).unwrap_return();
+    }
+
+    #[test]
+    fn test_state_into_result() {
+        assert_eq!(State::Yield::<u32, &str>(1).into_result(), Ok(1));
+        assert_eq!(
+            State::Return::<u32, &str>("done").into_result(),
+            Err("done")
+        );
+
+        let roundtrip: State<u32, &str> = State::Yield::<u32, &str>(1).into_result().into();
+        assert_eq!(roundtrip.unwrap_yield(), 1);
+
+        let roundtrip: State<u32, &str> = State::Return::<u32, &str>("done").into_result().into();
+        assert_eq!(roundtrip.unwrap_return(), "done");
+    }
+
+    #[test]
+    fn test_state_try() {
+        fn sum_yields<G>(g: &mut G, sum: &mut u32) -> State<(), G::Return>
+        where
+            G: Senerator<Yield = u32>,
+        {
+            loop {
+                *sum += g.resume_with_yield().expect("exhausted")?;
+            }
+        }
+
+        let mut sum = 0;
+        let ret = sum_yields(&mut Countdown::new(4), &mut sum);
+
+        assert_eq!(sum, 3 + 2 + 1);
+        assert_eq!(ret.unwrap_return(), "liftoff");
+    }
+
+    #[test]
+    fn test_callable_debug() {
+        let mut g = Callable::new(|| {
+            yield 1;
+        });
+
+        assert_eq!(format!("{:?}", g), "Callable { state: Active }");
+        g.take();
+        assert_eq!(format!("{:?}", g), "Callable { state: Exhausted }");
+    }
+
+    #[test]
+    fn test_callable_clone() {
+        let mut original = Callable::from_iter(0..3);
+        original.resume_with_yield();
+
+        let copy = original.clone();
+
+        assert_eq!(original.iter_yielded().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(copy.iter_yielded().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_callable_replace_set() {
+        let mut g = Callable::from_iter(vec![1, 2]);
+
+        assert!(g.take().is_some());
+        assert!(g.replace(vec![3, 4].into_generator()).is_none());
+        assert_eq!((&mut g).iter_yielded().collect::<Vec<_>>(), vec![3, 4]);
+
+        g.set(vec![5].into_generator());
+        assert!(g.replace(vec![7].into_generator()).is_some());
+        assert_eq!(g.iter_yielded().collect::<Vec<_>>(), vec![7]);
+    }
+
+    #[test]
+    fn test_callable_is_done_is_active() {
+        let mut g = Callable::new(|| {
+            yield 1;
+            return 2;
+        });
+
+        g.resume_with_yield();
+        assert!(g.is_active());
+        assert!(!g.is_done());
+
+        g.resume_with_yield();
+        assert!(!g.is_active());
+        assert!(g.is_done());
+    }
+
+    #[test]
+    fn test_collect_with_return() {
+        let (collected, ret): (Vec<_>, _) = Countdown::new(3).collect_with_return();
+
+        assert_eq!(collected, vec![2, 1, 0]);
+        assert_eq!(ret, "liftoff");
+    }
+
+    #[test]
+    fn test_fold_all() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return "summed";
+        });
+
+        assert_eq!(g.fold_all(0, |acc, y| acc + y), (6, "summed"));
+    }
+
+    #[test]
+    fn test_for_each_yield() {
+        let mut calls = 0;
+        let mut seen = Vec::new();
+
+        let ret = Countdown::new(3).for_each_yield(|y| {
+            calls += 1;
+            seen.push(y);
+        });
+
+        assert_eq!(ret, "liftoff");
+        assert_eq!(calls, 3);
+        assert_eq!(seen, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_count_yields() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return 99;
+        });
+
+        assert_eq!(g.count_yields(), (3, 99));
+    }
+
+    #[test]
+    fn test_nth_yield() {
+        let mut g = Countdown::new(5);
+
+        assert_eq!(g.nth_yield(1), Some(3));
+        assert_eq!(g.iter_yielded().collect::<Vec<_>>(), vec![2, 1, 0]);
+
+        assert_eq!(Countdown::new(2).nth_yield(2), None);
+    }
+
+    #[test]
+    fn test_find_yield() {
+        let mut g = Countdown::new(5);
+
+        assert_eq!(g.find_yield(|&y| y % 2 == 0 && y < 4), Some(2));
+        assert_eq!(g.iter_yielded().collect::<Vec<_>>(), vec![1, 0]);
+
+        assert_eq!(Countdown::new(5).find_yield(|&y| y > 10), None);
+    }
+
+    #[test]
+    fn test_position_yield() {
+        let mut g = Countdown::new(5);
+
+        assert_eq!(g.position_yield(|&y| y == 2), Some(2));
+        assert_eq!(g.position_yield(|&y| y == 0), Some(1));
+
+        assert_eq!(Countdown::new(5).position_yield(|&y| y > 10), None);
+    }
+
+    #[test]
+    fn test_any_yield() {
+        let mut g = Countdown::new(5);
+
+        assert!(g.any_yield(|&y| y == 3));
+        assert_eq!(g.count_yields(), (3, "liftoff"));
+
+        assert!(!Countdown::new(5).any_yield(|&y| y > 10));
+    }
+
+    #[test]
+    fn test_all_yield() {
+        let mut g = Countdown::new(5);
+
+        assert!(!g.all_yield(|&y| y > 2));
+        assert_eq!(g.count_yields(), (2, "liftoff"));
+
+        assert!(Countdown::new(5).all_yield(|&y| y < 5));
+    }
+
+    #[test]
+    fn test_max_by_key() {
+        let g = Callable::new(|| {
+            yield "bb";
+            yield "a";
+            yield "cc";
+            return 3;
+        });
+
+        assert_eq!(g.max_by_key(|s| s.len()), (Some("cc"), 3));
+        assert_eq!(empty::<u32, u32>().max_by_key(|&y| y), (None, 0));
+    }
+
+    #[test]
+    fn test_min_by_key() {
+        let g = Callable::new(|| {
+            yield "bb";
+            yield "a";
+            yield "c";
+            return 3;
+        });
+
+        assert_eq!(g.min_by_key(|s| s.len()), (Some("a"), 3));
+        assert_eq!(empty::<u32, u32>().min_by_key(|&y| y), (None, 0));
+    }
+
+    #[test]
+    fn test_sum_all() {
+        let g = Callable::new(|| {
+            yield 1;
+            yield 2;
+            yield 3;
+            return 99;
+        });
+
+        assert_eq!(g.sum_all(), 105);
+    }
+
+    #[test]
+    fn test_product_all() {
+        let g = Callable::new(|| {
+            yield 2;
+            yield 3;
+            return 4;
+        });
+
+        assert_eq!(g.product_all(), 24);
+    }
+
+    #[test]
+    fn test_last_yield() {
+        assert_eq!(Countdown::new(3).last_yield(), (Some(0), "liftoff"));
+        assert_eq!(Countdown::new(0).last_yield(), (None, "liftoff"));
+    }
+
+    #[test]
+    fn test_return_iterator_return_value() {
+        let mut iter = Callable::new(|| {
+            yield 1;
+            return 2;
+        })
+        .iter_all()
+        .keep_return();
+
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.return_value(), None);
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.return_value(), Some(&2));
+    }
+
+    #[test]
+    fn test_by_ref() {
+        let mut g = Countdown::new(4);
+
+        assert_eq!(
+            g.by_ref().iter_yielded().take(2).collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        assert_eq!(g.collect_with_return(), (vec![1, 0], "liftoff"));
+    }
+
+    #[test]
+    fn test_resume_n() {
+        let mut g = Countdown::new(5);
+
+        assert!(g.resume_n(0).is_none());
+        assert_eq!(g.resume_n(2).unwrap().unwrap_yield(), 3);
+        assert_eq!(g.resume_n(1).unwrap().unwrap_yield(), 2);
+        assert_eq!(g.resume_n(10).unwrap().unwrap_return(), "liftoff");
+    }
+
+    #[test]
+    fn test_drain() {
+        assert_eq!(Countdown::new(3).drain(), vec![2, 1, 0]);
+
+        assert_eq!(Callable::from_iter(vec![1, 2, 3]).drain(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_drain_exact() {
+        let drained = Callable::from_iter(vec![1, 2, 3]).drain_exact();
+
+        assert_eq!(drained, vec![1, 2, 3]);
+        assert_eq!(drained.capacity(), 3);
+    }
+
+    #[test]
+    fn test_fuse() {
+        let mut countdown = Countdown::new(1);
+
+        {
+            let mut g = (&mut countdown).fuse();
+
+            assert_eq!(g.resume_with_yield().unwrap().unwrap_yield(), 0);
+            assert_eq!(g.resume_with_yield().unwrap().unwrap_return(), "liftoff");
+
+            // Resuming the Countdown again would panic.
+            for _ in 0..3 {
+                assert!(g.resume_with_yield().is_none());
+                assert!(g.resume().is_none());
+            }
+        }
+
+        assert!(countdown.returned);
+    }
+
+    #[test]
+    fn test_senerator_object_safe() {
+        let mut countdown = Countdown::new(2);
+        let g: &mut dyn Senerator<Yield = u32, Return = &'static str> = &mut countdown;
+
+        assert_eq!(g.next_yield_or(9), 1);
+        assert_eq!(g.collect_exact(1), Some(vec![0]));
+        assert_eq!(g.resume_with_yield().unwrap().unwrap_return(), "liftoff");
+    }
+
+    #[cfg(feature = "extfutures")]
+    mod ext_futures {
+        extern crate futures;
+
+        use self::futures::executor::block_on;
+        use self::futures::StreamExt;
+        use gen::{Callable, Senerator};
+
+        #[test]
+        fn test_split_stream() {
+            let (stream, ret) = Callable::new(|| {
+                yield 1;
+                yield 2;
+                yield 3;
+                return "done";
+            })
+            .split_stream();
+
+            let yielded: Vec<i32> = block_on(stream.collect()).unwrap();
+            assert_eq!(yielded, vec![1, 2, 3]);
+            assert_eq!(block_on(ret), Ok("done"));
+        }
+    }
 }